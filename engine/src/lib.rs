@@ -1,9 +1,11 @@
 use serde::{Deserialize, Serialize};
 use wasm_bindgen::prelude::*;
-use regex::Regex;
+use regex::{Regex, RegexBuilder};
 use std::collections::HashMap;
 use hmac::{Hmac, Mac};
-use sha2::Sha256;
+use base64::Engine as _;
+use sha2::{Digest, Sha256};
+use sha3::Keccak256;
 
 #[derive(Clone, Debug)]
 struct ClaimedRegion {
@@ -11,6 +13,45 @@ struct ClaimedRegion {
     end: usize,
 }
 
+/// A span selected for redaction by a detector, in original-text coordinates.
+#[derive(Clone, Debug, Default)]
+struct Finding {
+    start: usize,
+    end: usize,
+    original: String,
+    /// Type prefix for the canonical id when it differs from the step's (e.g. URI components)
+    prefix: Option<String>,
+    /// Fixed output text, used instead of the redaction mode for composite findings
+    replacement: Option<String>,
+    /// Record the original in the map when the finding is applied, even though `replacement` is
+    /// fixed (generalized, truncated or shifted values the sidecar should map back)
+    record_original: bool,
+    /// Normalized form used for fingerprinting, so formatting variants share a canonical id
+    canonical: Option<String>,
+    /// Shannon entropy of the value, reported by the entropy detector
+    entropy: Option<f64>,
+    /// Heuristic score in 0..=1 for detectors that guess rather than validate
+    confidence: Option<f64>,
+}
+
+impl Finding {
+    fn new(start: usize, end: usize, original: &str) -> Self {
+        Self {
+            start,
+            end,
+            original: original.to_string(),
+            ..Default::default()
+        }
+    }
+
+    fn with_prefix(start: usize, end: usize, original: &str, prefix: String) -> Self {
+        Self {
+            prefix: Some(prefix),
+            ..Self::new(start, end, original)
+        }
+    }
+}
+
 #[derive(Serialize, Deserialize, Clone, Debug)]
 pub struct CanonicalEntry {
     pub id: String,
@@ -19,6 +60,13 @@ pub struct CanonicalEntry {
     pub fingerprint: String,
     pub occurrences: usize,
     pub contexts: Vec<String>,
+    /// Distinct original spellings that normalized to this entry
+    #[serde(default)]
+    pub variants: Vec<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub entropy: Option<f64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub confidence: Option<f64>,
 }
 
 #[derive(Serialize, Deserialize, Clone, Debug)]
@@ -44,6 +92,12 @@ pub struct Engine {
     claimed_regions: Vec<ClaimedRegion>,
 }
 
+impl Default for Engine {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 #[wasm_bindgen]
 impl Engine {
     #[wasm_bindgen(constructor)]
@@ -108,7 +162,12 @@ impl Engine {
             "uuid" => self.redact_uuid(text, &step.config, &type_prefix),
             "phone" => self.redact_phone(text, &step.config, &type_prefix),
             "ssn" => self.redact_ssn(text, &step.config, &type_prefix),
+            "national_id" | "nationalId" => self.redact_national_id(text, &step.config, &type_prefix),
             "credit_card" => self.redact_credit_card(text, &step.config, &type_prefix),
+            "iban" => self.redact_iban(text, &step.config, &type_prefix),
+            "bic" | "swift" => self.redact_bic(text, &step.config, &type_prefix),
+            "routing_number" | "routingNumber" => self.redact_routing_number(text, &step.config, &type_prefix),
+            "bank_account" | "bankAccount" => self.redact_bank_account(text, &step.config, &type_prefix),
             "api_key" | "apikey" => self.redact_api_key(text, &step.config, &type_prefix),
             "url" => self.redact_url(text, &step.config, &type_prefix),
             "username" => self.redact_username(text, &step.config, &type_prefix),
@@ -117,8 +176,25 @@ impl Engine {
             "query_param" | "queryParam" => self.redact_query_param(text, &step.config, &type_prefix),
             "http_header" | "header" => self.redact_http_header(text, &step.config, &type_prefix),
             "replace" => self.redact_replace(text, &step.config, &type_prefix),
+            "dictionary" => self.redact_dictionary(text, &step.config, &type_prefix),
+            "person_name" | "personName" => self.redact_person_name(text, &step.config, &type_prefix),
+            "address" => self.redact_address(text, &step.config, &type_prefix),
+            "date" => self.redact_date(text, &step.config, &type_prefix),
+            "geo" | "coordinates" => self.redact_geo(text, &step.config, &type_prefix),
+            "device" => self.redact_device(text, &step.config, &type_prefix),
+            "vin" => self.redact_vin(text, &step.config, &type_prefix),
+            "path" | "file_path" | "filePath" => self.redact_path(text, &step.config, &type_prefix),
+            "windows_identity" | "windowsIdentity" => self.redact_windows_identity(text, &step.config, &type_prefix),
+            "cloud_resource" | "cloudResource" => self.redact_cloud_resource(text, &step.config, &type_prefix),
+            "kubernetes" => self.redact_kubernetes(text, &step.config, &type_prefix),
+            "crypto_wallet" | "cryptoWallet" => self.redact_crypto_wallet(text, &step.config, &type_prefix),
             "partial_mask" | "partialMask" => self.redact_partial_mask(text, &step.config, &type_prefix),
             "oauth" => self.redact_oauth(text, &step.config, &type_prefix),
+            "entropy" => self.redact_entropy(text, &step.config, &type_prefix),
+            "connection_string" | "connectionString" => self.redact_connection_string(text, &step.config, &type_prefix),
+            "password" => self.redact_password(text, &step.config, &type_prefix),
+            "authorization" => self.redact_authorization(text, &step.config, &type_prefix),
+            "cookie" => self.redact_cookie(text, &step.config, &type_prefix),
             _ => Ok(text.to_string()),
         }
     }
@@ -135,7 +211,12 @@ impl Engine {
             "uuid" => "UUID",
             "phone" => "PHONE",
             "ssn" => "SSN",
+            "national_id" | "nationalId" => "ID",
             "credit_card" => "CC",
+            "iban" => "IBAN",
+            "bic" | "swift" => "BIC",
+            "routing_number" | "routingNumber" => "ROUTING",
+            "bank_account" | "bankAccount" => "ACCOUNT",
             "api_key" | "apikey" => "APIKEY",
             "url" => "URL",
             "username" => "USERNAME",
@@ -144,8 +225,25 @@ impl Engine {
             "query_param" | "queryParam" => "PARAM",
             "http_header" | "header" => "HEADER",
             "replace" => "REPLACE",
+            "dictionary" => "DICT",
+            "person_name" | "personName" => "NAME",
+            "address" => "ADDRESS",
+            "date" => "DATE",
+            "geo" | "coordinates" => "GEO",
+            "device" => "DEVICE",
+            "vin" => "VIN",
+            "path" | "file_path" | "filePath" => "PATH",
+            "windows_identity" | "windowsIdentity" => "WIN",
+            "cloud_resource" | "cloudResource" => "CLOUD",
+            "kubernetes" => "K8S",
+            "crypto_wallet" | "cryptoWallet" => "WALLET",
             "partial_mask" | "partialMask" => "MASK",
             "oauth" => "OAUTH",
+            "entropy" => "SECRET",
+            "connection_string" | "connectionString" => "URI",
+            "password" => "PASSWORD",
+            "authorization" => "AUTH",
+            "cookie" => "COOKIE",
             _ => "REDACTED",
         }.to_string()
     }
@@ -166,6 +264,362 @@ impl Engine {
         self.redact_with_regex(text, &regex, type_prefix, "replace", config)
     }
 
+    fn redact_dictionary(&mut self, text: &str, config: &serde_json::Value, type_prefix: &str) -> Result<String, JsValue> {
+        let terms: Vec<&str> = config.get("terms")
+            .and_then(|v| v.as_array())
+            .map(|terms| terms.iter().filter_map(|v| v.as_str()).map(str::trim).filter(|t| !t.is_empty()).collect())
+            .unwrap_or_default();
+
+        if terms.is_empty() {
+            return Ok(text.to_string());
+        }
+
+        let whole_word = config.get("wholeWord").and_then(|v| v.as_bool()).unwrap_or(true);
+        let case_insensitive = config.get("caseInsensitive").and_then(|v| v.as_bool()).unwrap_or(true);
+
+        // One regex over all terms, longest first so "Acme Corp" wins over "Acme" at the same start.
+        // The regex crate matches literal alternations with Aho-Corasick, and `(?i)` folds Unicode
+        // case (MÜLLER matches Müller), which the automaton's ASCII-only folding would not
+        let mut sorted = terms.clone();
+        sorted.sort_by_key(|t| std::cmp::Reverse(t.len()));
+        let is_word_char = |c: char| c.is_alphanumeric() || c == '_';
+        let alternation = sorted.iter()
+            .map(|term| {
+                if !whole_word {
+                    return regex::escape(term);
+                }
+                // A word edge needs \b; a punctuation edge (C++) must not run into a word either
+                let edge = |c: Option<char>| if c.is_some_and(is_word_char) { r"\b" } else { r"\B" };
+                format!("{}{}{}", edge(term.chars().next()), regex::escape(term), edge(term.chars().next_back()))
+            })
+            .collect::<Vec<_>>()
+            .join("|");
+        let regex = RegexBuilder::new(&alternation)
+            .case_insensitive(case_insensitive)
+            .size_limit(64 << 20)
+            .build()
+            .map_err(|e| JsValue::from_str(&format!("Invalid dictionary: {}", e)))?;
+
+        let findings: Vec<Finding> = regex.find_iter(text)
+            .map(|m| {
+                // Every spelling of a term shares the term's canonical id
+                let canonical = if case_insensitive { m.as_str().to_lowercase() } else { m.as_str().to_string() };
+                Finding {
+                    canonical: Some(canonical),
+                    ..Finding::new(m.start(), m.end(), m.as_str())
+                }
+            })
+            .collect();
+
+        self.redact_findings(text, findings, type_prefix, "dictionary", config)
+    }
+
+    fn redact_person_name(&mut self, text: &str, config: &serde_json::Value, type_prefix: &str) -> Result<String, JsValue> {
+        let min_confidence = config.get("minConfidence").and_then(|v| v.as_f64()).unwrap_or(0.6);
+
+        // Runs of up to four capitalized words (O'Brien, Jean-Pierre) with optional middle initials
+        let token = r"[A-Z](?:[a-z]+|'[A-Z][a-z]+)(?:-[A-Z][a-z]+)?";
+        let candidate_regex = Regex::new(&format!(r"\b{0}(?:[ \t]+(?:[A-Z]\.[ \t]+)?{0}){{0,3}}\b", token)).unwrap();
+        let token_regex = Regex::new(token).unwrap();
+
+        let first_names = name_ranks(FIRST_NAMES);
+        let surnames = name_ranks(SURNAMES);
+
+        let mut findings = Vec::new();
+        for m in candidate_regex.find_iter(text) {
+            let mut tokens: Vec<regex::Match> = token_regex.find_iter(m.as_str()).collect();
+
+            // Capitalized cue and stop words at the edges ("Dear John", "John Smith Error") aren't part of the name
+            while tokens.first().is_some_and(|t| is_name_stopword(t.as_str())) {
+                tokens.remove(0);
+            }
+            while tokens.last().is_some_and(|t| is_name_stopword(t.as_str())) {
+                tokens.pop();
+            }
+            let (Some(first), Some(last)) = (tokens.first(), tokens.last()) else {
+                continue;
+            };
+            if tokens.len() > 3 {
+                continue;
+            }
+
+            let start = m.start() + first.start();
+            let end = m.start() + last.end();
+
+            let mut confidence = 0.0;
+            if let Some(weight) = first_names.get(first.as_str()) {
+                confidence += 0.3 + 0.15 * weight;
+            }
+            if tokens.len() > 1 {
+                confidence += 0.1;
+                if let Some(weight) = surnames.get(last.as_str()) {
+                    confidence += 0.25 + 0.1 * weight;
+                }
+            } else if let Some(weight) = surnames.get(first.as_str()) {
+                confidence = f64::max(confidence, 0.25 + 0.1 * weight);
+            }
+
+            // Context: a title is near-certain, phrases like "assigned to" are weaker evidence
+            let window_start = text.floor_char_boundary(start.saturating_sub(24));
+            let before = text[window_start..start].to_lowercase();
+            let before = before.trim_end_matches([' ', '\t', ':', '=', '.', ',', '"', '\'']);
+            let ends_with_word = |cue: &&str| {
+                before.ends_with(*cue)
+                    && !before[..before.len() - cue.len()].chars().next_back().is_some_and(char::is_alphanumeric)
+            };
+            if ["mr", "mrs", "ms", "miss", "dr", "prof"].iter().any(ends_with_word) {
+                confidence += 0.6;
+            } else if NAME_CUES.iter().any(ends_with_word) {
+                confidence += 0.3;
+            } else if tokens.len() == 1 && (before.is_empty() || before.ends_with(['\n', '!', '?'])) {
+                // A lone capitalized word at the start of a line or sentence is weak evidence
+                confidence -= 0.15;
+            }
+
+            let confidence = f64::min(confidence, 1.0);
+            if confidence >= min_confidence {
+                findings.push(Finding {
+                    confidence: Some((confidence * 100.0).round() / 100.0),
+                    ..Finding::new(start, end, &text[start..end])
+                });
+            }
+        }
+
+        self.redact_findings(text, findings, type_prefix, "person_name", config)
+    }
+
+    fn redact_address(&mut self, text: &str, config: &serde_json::Value, type_prefix: &str) -> Result<String, JsValue> {
+        // "none" redacts postcodes like any other value; "postcode_prefix" keeps the leading area part,
+        // "country" keeps only the country the postcode belongs to
+        let generalize = config.get("generalize").and_then(|v| v.as_str()).unwrap_or("none");
+        let keep_digits = config.get("keepDigits").and_then(|v| v.as_u64()).unwrap_or(3) as usize;
+
+        let street_regex = Regex::new(r"\b\d{1,6}[A-Za-z]?\s+(?:(?:[A-Z][a-z]+|\d+(?:st|nd|rd|th)|[NSEW])\.?\s+){1,4}(?:Street|St|Avenue|Ave|Road|Rd|Boulevard|Blvd|Lane|Ln|Drive|Dr|Court|Ct|Place|Pl|Way|Terrace|Ter|Parkway|Pkwy|Circle|Cir|Highway|Hwy|Square|Sq|Close|Crescent|Cres|Gardens|Row|Mews)\b\.?(?:\s+(?:NE|NW|SE|SW|N|S|E|W)\b)?(?:,?\s*(?:Apt|Suite|Ste|Unit|Flat|#)\.?\s*[A-Za-z0-9-]+)?").unwrap();
+
+        // (country, pattern with the postcode in group 1); bare five-digit numbers need a state or keyword,
+        // UK postcodes a keyword, the start of a line or a comma (optionally then the town), Dutch
+        // postcodes a keyword or the place name after them
+        let postcode_patterns = [
+            ("US", r"\b(?:AL|AK|AZ|AR|CA|CO|CT|DE|DC|FL|GA|HI|ID|IL|IN|IA|KS|KY|LA|ME|MD|MA|MI|MN|MS|MO|MT|NE|NV|NH|NJ|NM|NY|NC|ND|OH|OK|OR|PA|RI|SC|SD|TN|TX|UT|VT|VA|WA|WV|WI|WY|PR),?\s+(\d{5}(?:-\d{4})?)\b"),
+            ("US", r"(?i)\bzip(?:\s*code)?\s*[:=#]?\s*(\d{5}(?:-\d{4})?)\b"),
+            ("GB", r"(?m)(?:^[ \t]*|,[ \t]*(?:(?:[A-Z][a-z]+|[A-Z]{2,})(?:[ -](?:[A-Z][a-z]+|[A-Z]{2,}))*,?[ \t]+)?)([A-Z]{1,2}\d[A-Z\d]?\s*\d[ABD-HJLNP-UW-Z]{2})\b"),
+            ("GB", r"(?i:\bpost\s*code)\s*[:=#]?\s*([A-Z]{1,2}\d[A-Z\d]?\s*\d[ABD-HJLNP-UW-Z]{2})\b"),
+            ("CA", r"\b([ABCEGHJ-NPRSTVXY]\d[ABCEGHJ-NPRSTV-Z]\s?\d[ABCEGHJ-NPRSTV-Z]\d)\b"),
+            ("NL", r"\b([1-9]\d{3}\s?[A-RT-Z][A-Z]),?[ \t]+(?:'s-)?[A-Z][a-z]"),
+            ("NL", r"(?i:\bpost(?:al)?\s*code)\s*[:=#]?\s*([1-9]\d{3}\s?[A-RT-Z][A-Z])\b"),
+            ("AU", r"\b(?:NSW|VIC|QLD|SA|WA|TAS|NT|ACT)\s+(\d{4})\b"),
+            ("", r"(?i)\b(?:postcode|postal\s*code|post\s*code|plz|cp)\s*[:=#]?\s*(\d{4,6})\b"),
+        ];
+
+        let postcode_prefix = format!("{}_POSTCODE", type_prefix);
+        let mut findings: Vec<Finding> = street_regex.find_iter(text)
+            .map(|m| Finding::new(m.start(), m.end(), m.as_str()))
+            .collect();
+
+        for (country, pattern) in postcode_patterns {
+            let regex = Regex::new(pattern).unwrap();
+            for cap in regex.captures_iter(text) {
+                let m = cap.get(1).unwrap();
+                let postcode = m.as_str();
+                let mut finding = Finding::with_prefix(m.start(), m.end(), postcode, postcode_prefix.clone());
+
+                let generalized = match generalize {
+                    "postcode_prefix" => Some(generalize_postcode(country, postcode, keep_digits)),
+                    "country" if !country.is_empty() => Some(country.to_string()),
+                    _ => None,
+                };
+                if let Some(generalized) = generalized {
+                    // Still recorded so the sidecar can map the generalized value back
+                    finding.replacement = Some(generalized);
+                    finding.record_original = true;
+                }
+                findings.push(finding);
+            }
+        }
+
+        self.redact_findings(text, findings, type_prefix, "address", config)
+    }
+
+    fn redact_geo(&mut self, text: &str, config: &serde_json::Value, type_prefix: &str) -> Result<String, JsValue> {
+        // "truncate" keeps coordinates to `decimals` places (2 is roughly 1 km) instead of removing them
+        let generalize = config.get("generalize").and_then(|v| v.as_str()).unwrap_or("none");
+        let decimals = config.get("decimals").and_then(|v| v.as_u64()).unwrap_or(2) as usize;
+        let truncate_to = (generalize == "truncate").then_some(decimals);
+
+        let mut findings = Vec::new();
+
+        // Keyed values: "lat": 51.5074, lat=51.5074&lon=-0.1278, longitude: -0.1278
+        let keyed_regex = Regex::new(r#"(?i)["']?\b(lat|latitude|lon|lng|long|longitude)["']?\s*[:=]\s*["']?(-?\d{1,3}(?:\.\d+)?)\b"#).unwrap();
+        for cap in keyed_regex.captures_iter(text) {
+            let m = cap.get(2).unwrap();
+            let limit = if cap[1].to_lowercase().starts_with("lat") { 90.0 } else { 180.0 };
+            if m.as_str().parse::<f64>().is_ok_and(|v| v.abs() <= limit) {
+                let finding = Finding::new(m.start(), m.end(), m.as_str());
+                findings.push(Self::truncate_coordinates(finding, vec![(0, m.len())], truncate_to));
+            }
+        }
+
+        // Bare decimal-degree pairs need enough precision (four places, ~10 m) and no version keyword
+        // in front to not be confused with version numbers
+        let pair_regex = Regex::new(r"(-?\d{1,2}\.\d{4,})\s*,\s*(-?\d{1,3}\.\d{4,})\b").unwrap();
+        for cap in pair_regex.captures_iter(text) {
+            let (whole, lat, lon) = (cap.get(0).unwrap(), cap.get(1).unwrap(), cap.get(2).unwrap());
+            if text[..whole.start()].ends_with(|c: char| c.is_alphanumeric() || c == '.')
+                || preceded_by_word(text, whole.start(), &["version", "ver", "v", "release", "build"], 16)
+            {
+                continue;
+            }
+            let (Ok(lat_value), Ok(lon_value)) = (lat.as_str().parse::<f64>(), lon.as_str().parse::<f64>()) else {
+                continue;
+            };
+            if lat_value.abs() > 90.0 || lon_value.abs() > 180.0 {
+                continue;
+            }
+            let parts = vec![(0, lat.len()), (lon.start() - whole.start(), lon.end() - whole.start())];
+            let finding = Finding {
+                canonical: Some(format!("{},{}", lat_value, lon_value)),
+                ..Finding::new(whole.start(), whole.end(), whole.as_str())
+            };
+            findings.push(Self::truncate_coordinates(finding, parts, truncate_to));
+        }
+
+        // Degrees, minutes, seconds: 51°30'26.5"N, 0°7'39"W
+        let dms_regex = Regex::new(r#"\b(\d{1,3})\s*°\s*(?:(\d{1,2}(?:\.\d+)?)\s*['′]\s*(?:(\d{1,2}(?:\.\d+)?)\s*(?:"|″|'')\s*)?)?([NSEW])\b"#).unwrap();
+        for cap in dms_regex.captures_iter(text) {
+            let whole = cap.get(0).unwrap();
+            let mut finding = Finding::new(whole.start(), whole.end(), whole.as_str());
+            if truncate_to.is_some() {
+                // Minutes are ~1.8 km and seconds ~30 m, so keep the unit matching the requested precision
+                let whole_part = |m: Option<regex::Match>| m.map(|m| m.as_str().split('.').next().unwrap().to_string());
+                let mut generalized = format!("{}°", &cap[1]);
+                if decimals >= 2 && let Some(minutes) = whole_part(cap.get(2)) {
+                    generalized.push_str(&format!("{}′", minutes));
+                    if decimals >= 4 && let Some(seconds) = whole_part(cap.get(3)) {
+                        generalized.push_str(&format!("{}″", seconds));
+                    }
+                }
+                generalized.push_str(&cap[4]);
+                finding.replacement = Some(generalized);
+                finding.record_original = true;
+            }
+            findings.push(finding);
+        }
+
+        // A pair also holds the keyed value it starts with (lat,lon=51.5074,-0.1278); the longer one
+        // goes first at equal starts so both coordinates are covered
+        findings.sort_by_key(|f| (f.start, std::cmp::Reverse(f.end)));
+
+        self.redact_findings(text, findings, type_prefix, "geo", config)
+    }
+
+    /// When `truncate_to` is set, cuts the numbers at `parts` (offsets within the finding) to that
+    /// many decimal places, keeping the original recordable so the sidecar can map it back.
+    fn truncate_coordinates(mut finding: Finding, parts: Vec<(usize, usize)>, truncate_to: Option<usize>) -> Finding {
+        let Some(decimals) = truncate_to else {
+            return finding;
+        };
+        let mut truncated = finding.original.clone();
+        for (part_start, part_end) in parts.into_iter().rev() {
+            let number = &finding.original[part_start..part_end];
+            let cut = match number.find('.') {
+                Some(dot) if decimals == 0 => &number[..dot],
+                Some(dot) => &number[..(dot + 1 + decimals).min(number.len())],
+                None => number,
+            };
+            truncated.replace_range(part_start..part_end, cut);
+        }
+        finding.replacement = Some(truncated);
+        finding.record_original = true;
+        finding
+    }
+
+    fn redact_date(&mut self, text: &str, config: &serde_json::Value, type_prefix: &str) -> Result<String, JsValue> {
+        // "redact" uses the redaction mode; "year" and "month" generalize; "shift" moves every date
+        // by the same per-session number of days so intervals between events are preserved
+        let date_mode = config.get("dateMode").and_then(|v| v.as_str()).unwrap_or("redact");
+        let day_first = config.get("dayFirst").and_then(|v| v.as_bool()).unwrap_or(false);
+        let max_shift = config.get("maxShiftDays").and_then(|v| v.as_u64()).unwrap_or(365).max(1) as i64;
+        let shift_days = self.date_shift_days(max_shift);
+
+        let months = r"(?P<mon>Jan(?:uary)?|Feb(?:ruary)?|Mar(?:ch)?|Apr(?:il)?|May|June?|July?|Aug(?:ust)?|Sept?(?:ember)?|Oct(?:ober)?|Nov(?:ember)?|Dec(?:ember)?)";
+        let time = r"(?:[T ]\d{2}:\d{2}(?::\d{2}(?:[.,]\d+)?)?(?:\s?(?:Z|[+-]\d{2}:?\d{2}|UTC|GMT|UT|[ECMP][SD]T))?)?";
+        let slash = if day_first {
+            r"\b(?P<d>\d{1,2})/(?P<m>\d{1,2})/(?P<y>\d{4})\b"
+        } else {
+            r"\b(?P<m>\d{1,2})/(?P<d>\d{1,2})/(?P<y>\d{4})\b"
+        };
+        let patterns = [
+            // ISO 8601 dates and timestamps
+            format!(r"\b(?P<y>\d{{4}})-(?P<m>\d{{2}})-(?P<d>\d{{2}}){}\b", time),
+            // Apache/nginx access logs: 15/Jan/2024:10:23:45 +0000
+            format!(r"\b(?P<d>\d{{2}})/{}/(?P<y>\d{{4}}):\d{{2}}:\d{{2}}:\d{{2}}(?: [+-]\d{{4}})?", months),
+            // RFC 2822 and "15 January 2024"
+            format!(r"\b(?:(?P<wd>Mon|Tue|Wed|Thu|Fri|Sat|Sun),?\s+)?(?P<d>\d{{1,2}})\s+{}\s+(?P<y>\d{{4}}){}\b", months, time),
+            // "January 15, 2024"
+            format!(r"\b{}\s+(?P<d>\d{{1,2}})(?:st|nd|rd|th)?,?\s+(?P<y>\d{{4}})\b", months),
+            // US (or day-first) slashes and European dots
+            slash.to_string(),
+            r"\b(?P<d>\d{1,2})\.(?P<m>\d{1,2})\.(?P<y>\d{4})\b".to_string(),
+            // Syslog timestamps carry no year: Jan 15 10:23:45
+            format!(r"\b{}\s+(?P<d>\d{{1,2}})\s+\d{{2}}:\d{{2}}:\d{{2}}\b", months),
+        ];
+
+        let mut findings = Vec::new();
+        for pattern in &patterns {
+            let regex = Regex::new(pattern).unwrap();
+            for cap in regex.captures_iter(text) {
+                let whole = cap.get(0).unwrap();
+                let year = cap.name("y").and_then(|y| y.as_str().parse::<i64>().ok());
+                let month = match (cap.name("m"), cap.name("mon")) {
+                    (Some(m), _) => m.as_str().parse::<u32>().ok(),
+                    (None, Some(mon)) => month_from_name(mon.as_str()),
+                    _ => None,
+                };
+                let day = cap.name("d").and_then(|d| d.as_str().parse::<u32>().ok());
+                let (Some(month), Some(day)) = (month, day) else {
+                    continue;
+                };
+                // Yearless syslog dates are checked against a leap year so Feb 29 stays valid
+                if !(1..=12).contains(&month) || day == 0 || day > days_in_month(year.unwrap_or(2000), month) {
+                    continue;
+                }
+
+                let mut finding = Finding::new(whole.start(), whole.end(), whole.as_str());
+
+                // Plain dates in any format share a canonical id; timestamps keep their own
+                let date_end = ["y", "m", "mon", "d"].iter().filter_map(|g| cap.name(g)).map(|g| g.end()).max().unwrap();
+                if let Some(year) = year
+                    && date_end == whole.end()
+                    && cap.name("wd").is_none()
+                {
+                    finding.canonical = Some(format!("{:04}-{:02}-{:02}", year, month, day));
+                }
+
+                let replacement = match (date_mode, year) {
+                    ("year", Some(year)) => Some(format!("{:04}", year)),
+                    ("month", Some(year)) => Some(format!("{:04}-{:02}", year, month)),
+                    ("shift", _) => Some(shift_date(&cap, year.unwrap_or(2000), month, day, shift_days)),
+                    _ => None,
+                };
+                if let Some(replacement) = replacement {
+                    finding.replacement = Some(replacement);
+                    finding.record_original = true;
+                }
+                findings.push(finding);
+            }
+        }
+
+        self.redact_findings(text, findings, type_prefix, "date", config)
+    }
+
+    /// Non-zero day offset in `-max..=max`, fixed for the lifetime of the session secret.
+    fn date_shift_days(&self, max: i64) -> i64 {
+        let fingerprint = self.generate_fingerprint("date-shift");
+        let seed = u64::from_str_radix(&fingerprint[..16], 16).unwrap();
+        let magnitude = (seed % max as u64) as i64 + 1;
+        if seed >> 63 == 1 { -magnitude } else { magnitude }
+    }
+
     fn redact_partial_mask(&mut self, text: &str, config: &serde_json::Value, type_prefix: &str) -> Result<String, JsValue> {
         let start = config.get("start").and_then(|v| v.as_u64()).unwrap_or(0) as usize;
         let end = config.get("end").and_then(|v| v.as_u64()).unwrap_or(text.len() as u64) as usize;
@@ -199,6 +653,9 @@ impl Engine {
                 fingerprint: fingerprint.clone(),
                 occurrences: 0,
                 contexts: vec![],
+                variants: vec![],
+                entropy: None,
+                confidence: None,
             }
         });
 
@@ -232,7 +689,7 @@ impl Engine {
 
         let keys_pattern = keys.iter()
             .filter_map(|v| v.as_str())
-            .map(|s| regex::escape(s))
+            .map(regex::escape)
             .collect::<Vec<_>>()
             .join("|");
 
@@ -259,7 +716,7 @@ impl Engine {
 
         let names_pattern = names.iter()
             .filter_map(|v| v.as_str())
-            .map(|s| regex::escape(s))
+            .map(regex::escape)
             .collect::<Vec<_>>()
             .join("|");
 
@@ -274,6 +731,47 @@ impl Engine {
         self.redact_captures(text, &regex, 1, type_prefix, "query_param", config)
     }
 
+    fn redact_password(&mut self, text: &str, config: &serde_json::Value, type_prefix: &str) -> Result<String, JsValue> {
+        let keys: Vec<String> = config.get("keys")
+            .and_then(|v| v.as_array())
+            .map(|keys| keys.iter().filter_map(|v| v.as_str()).map(regex::escape).collect::<Vec<_>>())
+            .filter(|keys| !keys.is_empty())
+            .unwrap_or_else(|| {
+                ["password", "passwd", "pass", "secret", "token", "credential", "private_key", "privatekey", "api_key", "apikey", "access_key", "client_secret"]
+                    .iter()
+                    .map(|s| s.to_string())
+                    .collect()
+            });
+        // A vocabulary word has to end the key (DB_PASS, client_secret, access_token), optionally
+        // followed by a key/value segment (secret_key). Short words also have to start a segment so
+        // bypass=, compass: and max_tokens= don't match; longer ones may be glued on (PGPASSWORD)
+        let all = keys.join("|");
+        let long = keys.iter().filter(|k| k.len() >= 6).cloned().collect::<Vec<_>>().join("|");
+        let glued = if long.is_empty() { String::new() } else { format!("|[a-z0-9_.-]*(?:{})", long) };
+        let key = format!(r"(?:(?:[a-z0-9_.-]*[_.-])?(?:{}){})(?:[_.-]?(?:key|value))?", all, glued);
+
+        // Followed by `=`, `:` or, for CLI flags, whitespace. Only the value is captured.
+        let pattern = format!(
+            r#"(?im)(?:^|[^a-z0-9_.-])(?:--{0}(?:\s+|=)|["']?{0}["']?\s*[:=]\s*)(?:"([^"\r\n]*)"|'([^'\r\n]*)'|([^\s,;&"'}}\]]+))"#,
+            key
+        );
+        let regex = Regex::new(&pattern).unwrap();
+
+        let findings: Vec<Finding> = regex.captures_iter(text)
+            .filter_map(|cap| cap.get(1).or_else(|| cap.get(2)).or_else(|| cap.get(3)))
+            .filter(|m| {
+                // Skip empty values, flags, variable references and values already redacted
+                let value = m.as_str();
+                !value.is_empty()
+                    && !value.starts_with(['-', '$', '<', '%'])
+                    && !value.chars().all(|c| c == '*')
+            })
+            .map(|m| Finding::new(m.start(), m.end(), m.as_str()))
+            .collect();
+
+        self.redact_findings(text, findings, type_prefix, "password", config)
+    }
+
     fn redact_http_header(&mut self, text: &str, config: &serde_json::Value, type_prefix: &str) -> Result<String, JsValue> {
         let names = config.get("names")
             .and_then(|v| v.as_array())
@@ -286,7 +784,7 @@ impl Engine {
 
         let names_pattern = names.iter()
             .filter_map(|v| v.as_str())
-            .map(|s| regex::escape(s))
+            .map(regex::escape)
             .collect::<Vec<_>>()
             .join("|");
 
@@ -301,67 +799,177 @@ impl Engine {
         self.redact_captures(text, &regex, 1, type_prefix, "http_header", config)
     }
 
-    fn redact_captures(&mut self, text: &str, regex: &Regex, capture_group: usize, type_upper: &str, type_lower: &str, config: &serde_json::Value) -> Result<String, JsValue> {
-        let mut result = text.to_string();
-        let mut offset: i32 = 0;
-        
-        let matches: Vec<(usize, usize, String)> = regex.captures_iter(text)
-            .filter_map(|cap| {
-                cap.get(capture_group).and_then(|m| {
-                    let start = m.start();
-                    let end = m.end();
-                    if self.is_region_claimed(start, end) {
-                        None
-                    } else {
-                        Some((start, end, m.as_str().to_string()))
+    fn redact_authorization(&mut self, text: &str, config: &serde_json::Value, type_prefix: &str) -> Result<String, JsValue> {
+        let redact_user = config.get("redactUser").and_then(|v| v.as_bool()).unwrap_or(true);
+
+        // Authorization / Proxy-Authorization as a header line, JSON key or key=value
+        let header_regex = Regex::new(r#"(?i)\b(?:proxy-)?authorization["']?\s*[:=]\s*(?:["']([^\r\n"']+)|([^\r\n]+))"#).unwrap();
+        // Bearer tokens in free text, where there is no header name at all
+        let bearer_regex = Regex::new(r"(?i)\bbearer\s+([A-Za-z0-9._~+/-]{8,}=*)").unwrap();
+        let digest_param_regex = Regex::new(r#"([A-Za-z-]+)\s*=\s*(?:"([^"]*)"|([^\s,]+))"#).unwrap();
+
+        let user_prefix = format!("{}_USER", type_prefix);
+        let password_prefix = format!("{}_PASSWORD", type_prefix);
+
+        let mut findings = Vec::new();
+        for cap in header_regex.captures_iter(text) {
+            let value = cap.get(1).or_else(|| cap.get(2)).unwrap();
+            let trimmed = value.as_str().trim_end();
+
+            // Keep the scheme visible; credentials follow the first run of whitespace
+            let (scheme, credentials_offset) = match trimmed.find(char::is_whitespace) {
+                Some(space) => {
+                    let skipped = trimmed[space..].len() - trimmed[space..].trim_start().len();
+                    (trimmed[..space].to_lowercase(), space + skipped)
+                }
+                None => (String::new(), 0),
+            };
+            let start = value.start() + credentials_offset;
+            let credentials = &trimmed[credentials_offset..];
+            if credentials.is_empty() {
+                continue;
+            }
+
+            match scheme.as_str() {
+                "basic" => {
+                    let decoded = base64::engine::general_purpose::STANDARD.decode(credentials).ok()
+                        .and_then(|bytes| String::from_utf8(bytes).ok());
+                    match decoded.as_deref().and_then(|d| d.split_once(':')) {
+                        Some((user, password)) => {
+                            // Replace the encoded blob with user:password, each part redacted on its own
+                            let end = start + credentials.len();
+                            let user_part = if redact_user && !user.is_empty() {
+                                let part = Finding::with_prefix(start, end, user, user_prefix.clone());
+                                let id = self.record_finding(text, &part, type_prefix, "authorization");
+                                self.apply_redaction_mode(user, &id, config)
+                            } else {
+                                user.to_string()
+                            };
+                            let password_part = Finding::with_prefix(start, end, password, password_prefix.clone());
+                            let id = self.record_finding(text, &password_part, type_prefix, "authorization");
+                            let password_part = self.apply_redaction_mode(password, &id, config);
+
+                            findings.push(Finding {
+                                replacement: Some(format!("{}:{}", user_part, password_part)),
+                                ..Finding::new(start, end, credentials)
+                            });
+                        }
+                        None => findings.push(Finding::new(start, start + credentials.len(), credentials)),
+                    }
+                }
+                "digest" => {
+                    // Keep realm, uri, qop, algorithm and nc; redact identity and challenge material
+                    for param in digest_param_regex.captures_iter(credentials) {
+                        let name = param[1].to_lowercase();
+                        let m = param.get(2).or_else(|| param.get(3)).unwrap();
+                        if m.as_str().is_empty() {
+                            continue;
+                        }
+                        match name.as_str() {
+                            "username" if redact_user => {
+                                findings.push(Finding::with_prefix(start + m.start(), start + m.end(), m.as_str(), user_prefix.clone()));
+                            }
+                            "response" | "nonce" | "cnonce" | "opaque" => {
+                                findings.push(Finding::new(start + m.start(), start + m.end(), m.as_str()));
+                            }
+                            _ => {}
+                        }
                     }
-                })
+                }
+                _ => findings.push(Finding::new(start, start + credentials.len(), credentials)),
+            }
+        }
+
+        for cap in bearer_regex.captures_iter(text) {
+            let m = cap.get(1).unwrap();
+            findings.push(Finding::new(m.start(), m.end(), m.as_str()));
+        }
+
+        self.redact_findings(text, findings, type_prefix, "authorization", config)
+    }
+
+    fn redact_cookie(&mut self, text: &str, config: &serde_json::Value, type_prefix: &str) -> Result<String, JsValue> {
+        // Cookie name patterns with `*` wildcards; no patterns means every cookie
+        let name_patterns: Vec<Regex> = config.get("names")
+            .and_then(|v| v.as_array())
+            .map(|names| {
+                names.iter()
+                    .filter_map(|v| v.as_str())
+                    .filter_map(|p| Regex::new(&format!("(?i)^{}$", regex::escape(p).replace(r"\*", ".*"))).ok())
+                    .collect()
             })
-            .collect();
+            .unwrap_or_default();
 
-        for (start, end, original) in matches {
-            let fingerprint = self.generate_fingerprint(&original);
-            let context_start = if start >= 20 { start - 20 } else { 0 };
-            let context_end = if end + 20 <= text.len() { end + 20 } else { text.len() };
-            let context = text[context_start..context_end].to_string();
-            
-            let entry = self.canonical_map.entry(fingerprint.clone()).or_insert_with(|| {
-                let r_type = type_upper.to_string();
-                let count = self.next_ids.entry(r_type.clone()).or_insert(1);
-                let id = format!("{}_{}", r_type, count);
-                *count += 1;
-                
-                CanonicalEntry {
-                    id,
-                    r#type: type_lower.to_string(),
-                    original: original.clone(),
-                    fingerprint: fingerprint.clone(),
-                    occurrences: 0,
-                    contexts: vec![],
+        // Header lines (Cookie: a=1; b=2) and HAR header objects ({"name": "Cookie", "value": "a=1"})
+        let header_regex = Regex::new(r#"(?i)\b(set-cookie|cookie)["']?\s*:\s*(?:"([^"\r\n]*)"|([^\r\n]+))"#).unwrap();
+        let har_regex = Regex::new(r#"(?i)"name"\s*:\s*"(set-cookie|cookie)"\s*,\s*"value"\s*:\s*"([^"]*)""#).unwrap();
+
+        let mut headers: Vec<(bool, usize, &str)> = Vec::new();
+        for cap in header_regex.captures_iter(text).chain(har_regex.captures_iter(text)) {
+            let is_set_cookie = cap[1].eq_ignore_ascii_case("set-cookie");
+            if let Some(value) = cap.get(2).or_else(|| cap.get(3)) {
+                headers.push((is_set_cookie, value.start(), value.as_str()));
+            }
+        }
+
+        let mut findings = Vec::new();
+        for (is_set_cookie, value_start, value) in headers {
+            let mut pos = value_start;
+            for (index, pair) in value.split(';').enumerate() {
+                let pair_start = pos;
+                pos += pair.len() + 1;
+
+                // Set-Cookie carries a single cookie; everything after it is attributes (Path, Expires, HttpOnly)
+                if is_set_cookie && index > 0 {
+                    break;
                 }
-            });
 
-            entry.occurrences += 1;
-            if entry.contexts.len() < 3 && !entry.contexts.contains(&context) {
-                entry.contexts.push(context);
+                let Some(eq) = pair.find('=') else {
+                    continue;
+                };
+                let name = pair[..eq].trim();
+                let raw_value = &pair[eq + 1..];
+                if !name_patterns.is_empty() && !name_patterns.iter().any(|r| r.is_match(name)) {
+                    continue;
+                }
+
+                let leading = raw_value.len() - raw_value.trim_start().len();
+                let cookie_value = raw_value.trim().trim_matches('"');
+                if cookie_value.is_empty() {
+                    continue;
+                }
+
+                let quote = usize::from(raw_value.trim_start().starts_with('"'));
+                let start = pair_start + eq + 1 + leading + quote;
+                findings.push(Finding::new(start, start + cookie_value.len(), cookie_value));
             }
-            
-            let canonical_id = entry.id.clone();
-            let replacement = self.apply_redaction_mode(&original, &canonical_id, config);
-            
-            let actual_start = (start as i32 + offset) as usize;
-            let actual_end = (end as i32 + offset) as usize;
-            
-            result.replace_range(actual_start..actual_end, &replacement);
-            offset += replacement.len() as i32 - (end - start) as i32;
-            
-            self.claimed_regions.push(ClaimedRegion {
-                start,
-                end,
-            });
         }
 
-        Ok(result)
+        // HAR request.cookies/response.cookies arrays repeat the header cookies as {"name": "sid", "value": "abc"}
+        let cookies_regex = Regex::new(r#""cookies"\s*:\s*\["#).unwrap();
+        let entry_regex = Regex::new(r#""name"\s*:\s*"((?:[^"\\\r\n]|\\.)*)"\s*,\s*"value"\s*:\s*"((?:[^"\\\r\n]|\\.)*)""#).unwrap();
+        for array in cookies_regex.find_iter(text) {
+            let array_end = json_array_end(text, array.end() - 1);
+            for cap in entry_regex.captures_iter(&text[array.end()..array_end]) {
+                let (name, value) = (&cap[1], cap.get(2).unwrap());
+                if value.is_empty() || (!name_patterns.is_empty() && !name_patterns.iter().any(|r| r.is_match(name))) {
+                    continue;
+                }
+                let start = array.end() + value.start();
+                findings.push(Finding::new(start, start + value.len(), value.as_str()));
+            }
+        }
+
+        self.redact_findings(text, findings, type_prefix, "cookie", config)
+    }
+
+    fn redact_captures(&mut self, text: &str, regex: &Regex, capture_group: usize, type_upper: &str, type_lower: &str, config: &serde_json::Value) -> Result<String, JsValue> {
+        let findings: Vec<Finding> = regex.captures_iter(text)
+            .filter_map(|cap| cap.get(capture_group))
+            .map(|m| Finding::new(m.start(), m.end(), m.as_str()))
+            .collect();
+
+        self.redact_findings(text, findings, type_upper, type_lower, config)
     }
 
     fn redact_email(&mut self, text: &str, _config: &serde_json::Value, type_prefix: &str) -> Result<String, JsValue> {
@@ -386,14 +994,84 @@ impl Engine {
         self.redact_with_regex(text, &regex, type_prefix, "regex", config)
     }
 
-    fn redact_ipv4(&mut self, text: &str, _config: &serde_json::Value, type_prefix: &str) -> Result<String, JsValue> {
+    fn redact_ipv4(&mut self, text: &str, config: &serde_json::Value, type_prefix: &str) -> Result<String, JsValue> {
         let ipv4_regex = Regex::new(r"\b(?:(?:25[0-5]|2[0-4][0-9]|[01]?[0-9][0-9]?)\.){3}(?:25[0-5]|2[0-4][0-9]|[01]?[0-9][0-9]?)\b").unwrap();
-        self.redact_with_regex(text, &ipv4_regex, type_prefix, "ipv4", _config)
+        let findings = ipv4_regex.find_iter(text)
+            .map(|m| Finding::new(m.start(), m.end(), m.as_str()))
+            .collect();
+        let findings = self.prefix_preserving_findings(findings, "ipv4", config);
+        self.redact_findings(text, findings, type_prefix, "ipv4", config)
     }
 
-    fn redact_jwt(&mut self, text: &str, _config: &serde_json::Value, type_prefix: &str) -> Result<String, JsValue> {
+    fn redact_jwt(&mut self, text: &str, config: &serde_json::Value, type_prefix: &str) -> Result<String, JsValue> {
         let jwt_regex = Regex::new(r"eyJ[A-Za-z0-9_-]+\.eyJ[A-Za-z0-9_-]+\.[A-Za-z0-9_-]+").unwrap();
-        self.redact_with_regex(text, &jwt_regex, type_prefix, "jwt", _config)
+
+        if !config.get("partial").and_then(|v| v.as_bool()).unwrap_or(false) {
+            return self.redact_with_regex(text, &jwt_regex, type_prefix, "jwt", config);
+        }
+
+        let claims: Vec<String> = config.get("claims")
+            .and_then(|v| v.as_array())
+            .map(|claims| claims.iter().filter_map(|v| v.as_str()).map(|s| s.to_string()).collect::<Vec<_>>())
+            .filter(|claims| !claims.is_empty())
+            .unwrap_or_else(|| vec!["sub".to_string(), "email".to_string(), "name".to_string()]);
+        let summary = config.get("output").and_then(|v| v.as_str()) == Some("summary");
+
+        let mut findings = Vec::new();
+        for m in jwt_regex.find_iter(text) {
+            let token = m.as_str();
+            let segments: Vec<&str> = token.split('.').collect();
+            let decode = |segment: &str| -> Option<serde_json::Map<String, serde_json::Value>> {
+                let bytes = base64::engine::general_purpose::URL_SAFE_NO_PAD.decode(segment.trim_end_matches('=')).ok()?;
+                serde_json::from_slice(&bytes).ok()
+            };
+
+            // Tokens that don't decode are redacted whole, as in the default mode
+            let (Some(header), Some(mut payload)) = (decode(segments[0]), decode(segments[1])) else {
+                findings.push(Finding::new(m.start(), m.end(), token));
+                continue;
+            };
+
+            // The whole token is the canonical value behind the invalidated signature
+            let token_id = self.record_finding(text, &Finding::new(m.start(), m.end(), token), type_prefix, "jwt");
+
+            for claim in &claims {
+                let Some(value) = payload.get(claim) else {
+                    continue;
+                };
+                let original = match value {
+                    serde_json::Value::String(s) => s.clone(),
+                    other => other.to_string(),
+                };
+                let part = Finding::with_prefix(m.start(), m.end(), &original, format!("{}_{}", type_prefix, claim.to_uppercase()));
+                let id = self.record_finding(text, &part, type_prefix, "jwt");
+                payload.insert(claim.clone(), serde_json::Value::String(self.apply_redaction_mode(&original, &id, config)));
+            }
+
+            let replacement = if summary {
+                serde_json::json!({
+                    "header": header,
+                    "payload": payload,
+                    "signature": format!("<{}>", token_id),
+                }).to_string()
+            } else {
+                // Re-encode with the canonical id as the signature, which can never verify
+                let payload_json = serde_json::Value::Object(payload).to_string();
+                format!(
+                    "{}.{}.{}",
+                    segments[0],
+                    base64::engine::general_purpose::URL_SAFE_NO_PAD.encode(payload_json),
+                    token_id
+                )
+            };
+
+            findings.push(Finding {
+                replacement: Some(replacement),
+                ..Finding::new(m.start(), m.end(), token)
+            });
+        }
+
+        self.redact_findings(text, findings, type_prefix, "jwt", config)
     }
 
     fn redact_uuid(&mut self, text: &str, _config: &serde_json::Value, type_prefix: &str) -> Result<String, JsValue> {
@@ -401,9 +1079,43 @@ impl Engine {
         self.redact_with_regex(text, &uuid_regex, type_prefix, "uuid", _config)
     }
 
-    fn redact_phone(&mut self, text: &str, _config: &serde_json::Value, type_prefix: &str) -> Result<String, JsValue> {
-        let phone_regex = Regex::new(r"(?:\+\d{1,3}\s?)?(?:\(\d{3}\)|\d{3})[\s.-]?\d{3}[\s.-]?\d{4}").unwrap();
-        self.redact_with_regex(text, &phone_regex, type_prefix, "phone", _config)
+    fn redact_phone(&mut self, text: &str, config: &serde_json::Value, type_prefix: &str) -> Result<String, JsValue> {
+        let default_region = config.get("defaultRegion").and_then(|v| v.as_str()).unwrap_or("US").to_uppercase();
+
+        // Loose candidates; each is validated against the numbering plan of its country
+        let phone_regex = Regex::new(r"(?:\+|\b00|\()?\d[\d(). -]{5,22}\d").unwrap();
+        let not_phone_regex = Regex::new(r"^(?:\d{1,3}\.){3}\d{1,3}$|^\d{4}[-./]\d{1,2}[-./]\d{1,2}|^\d{1,2}[-./]\d{1,2}[-./]\d{2,4}$").unwrap();
+
+        let mut findings = Vec::new();
+        for m in phone_regex.find_iter(text) {
+            let preceding = text[..m.start()].chars().next_back();
+            let following = text[m.end()..].chars().next();
+            if preceding.is_some_and(|c| c.is_alphanumeric() || matches!(c, '.' | '/' | '-' | '_' | '+'))
+                || following.is_some_and(|c| c.is_alphanumeric())
+            {
+                continue;
+            }
+
+            // A candidate may run into a trailing number ("555-010-0199 2024"), so drop groups until it validates
+            let mut candidate = m.as_str();
+            loop {
+                if !not_phone_regex.is_match(candidate)
+                    && let Some(e164) = normalize_phone(candidate, &default_region)
+                {
+                    findings.push(Finding {
+                        canonical: Some(e164),
+                        ..Finding::new(m.start(), m.start() + candidate.len(), candidate)
+                    });
+                    break;
+                }
+                match candidate.rfind(' ') {
+                    Some(space) => candidate = candidate[..space].trim_end_matches([' ', '-', '.', '(']),
+                    None => break,
+                }
+            }
+        }
+
+        self.redact_findings(text, findings, type_prefix, "phone", config)
     }
 
     fn redact_ssn(&mut self, text: &str, _config: &serde_json::Value, type_prefix: &str) -> Result<String, JsValue> {
@@ -411,11 +1123,255 @@ impl Engine {
         self.redact_with_regex(text, &ssn_regex, type_prefix, "ssn", _config)
     }
 
+    fn redact_national_id(&mut self, text: &str, config: &serde_json::Value, type_prefix: &str) -> Result<String, JsValue> {
+        // Per-country toggles; every country in the pack is on unless a list is given
+        let countries: Vec<String> = config.get("countries")
+            .and_then(|v| v.as_array())
+            .map(|c| c.iter().filter_map(|v| v.as_str()).map(|s| s.to_uppercase()).collect())
+            .unwrap_or_else(|| ["GB", "CA", "IN", "AU", "ES", "IT", "FR", "BR"].iter().map(|s| s.to_string()).collect());
+
+        let require_context = config.get("requireContext").and_then(|v| v.as_bool()).unwrap_or(true);
+
+        let mut findings = Vec::new();
+        for format in NATIONAL_ID_FORMATS {
+            if !countries.iter().any(|c| c == format.country) {
+                continue;
+            }
+            let regex = Regex::new(format.pattern).unwrap();
+            let prefix = format!("{}_{}", type_prefix, format.kind);
+            findings.extend(regex.find_iter(text)
+                .filter(|m| (format.is_valid)(m.as_str()))
+                // Bare-digit formats with a weak checksum pass about one random number in ten
+                .filter(|m| !require_context || format.keywords.is_empty() || preceded_by_word(text, m.start(), format.keywords, 32))
+                .map(|m| Finding::with_prefix(m.start(), m.end(), m.as_str(), prefix.clone())));
+        }
+
+        self.redact_findings(text, findings, type_prefix, "national_id", config)
+    }
+
+    fn redact_device(&mut self, text: &str, config: &serde_json::Value, type_prefix: &str) -> Result<String, JsValue> {
+        // Per-identifier toggles; the whole device group is on unless a list is given
+        let identifiers: Vec<String> = config.get("identifiers")
+            .and_then(|v| v.as_array())
+            .map(|c| c.iter().filter_map(|v| v.as_str()).map(|s| s.to_lowercase()).collect())
+            .unwrap_or_else(|| ["imei", "imsi", "iccid", "advertising_id", "serial", "mac"].iter().map(|s| s.to_string()).collect());
+        let enabled = |id: &str| identifiers.iter().any(|i| i == id);
+
+        let mut findings = Vec::new();
+        let mut push = |m: regex::Match, kind: &str| {
+            findings.push(Finding::with_prefix(m.start(), m.end(), m.as_str(), format!("{}_{}", type_prefix, kind)));
+        };
+
+        if enabled("imei") {
+            // 15 digits, optionally printed as AA-BBBBBB-CCCCCC-D. Luhn alone passes one random number in ten,
+            // so also require an "imei" keyword or a common reporting-body prefix
+            let imei_regex = Regex::new(r"\b\d{2}[- ]?\d{6}[- ]?\d{6}[- ]?\d\b").unwrap();
+            for m in imei_regex.find_iter(text) {
+                let digits = digits_of(m.as_str());
+                let known_prefix = ["01", "35", "86", "99"].iter().any(|p| m.as_str().starts_with(p));
+                if digits.len() == 15 && luhn_valid(&digits)
+                    && (known_prefix || preceded_by_keyword(text, m.start(), &["imei"], 24))
+                {
+                    push(m, "IMEI");
+                }
+            }
+        }
+
+        if enabled("imsi") {
+            // MCC + MNC + MSIN has no check digit, so only keyworded values are taken
+            let imsi_regex = Regex::new(r"\b[2-7]\d{14}\b").unwrap();
+            for m in imsi_regex.find_iter(text) {
+                if preceded_by_keyword(text, m.start(), &["imsi"], 24) {
+                    push(m, "IMSI");
+                }
+            }
+        }
+
+        if enabled("iccid") {
+            // SIM serials start with the telecom industry id 89; some readers append an F filler
+            let iccid_regex = Regex::new(r"\b89\d{16,18}F?\b").unwrap();
+            for m in iccid_regex.find_iter(text) {
+                if luhn_valid(&digits_of(m.as_str())) {
+                    push(m, "ICCID");
+                }
+            }
+        }
+
+        if enabled("advertising_id") {
+            // IDFA/IDFV/GAID are plain UUIDs; the all-zero id means tracking is limited and identifies no one
+            let uuid_regex = Regex::new(r"(?i)\b[0-9a-f]{8}-[0-9a-f]{4}-[0-9a-f]{4}-[0-9a-f]{4}-[0-9a-f]{12}\b").unwrap();
+            let keywords = ["idfa", "idfv", "gaid", "aaid", "adid", "ad_id", "advertising", "ad id"];
+            for m in uuid_regex.find_iter(text) {
+                if m.as_str().chars().any(|c| c != '0' && c != '-') && preceded_by_keyword(text, m.start(), &keywords, 32) {
+                    push(m, "ADID");
+                }
+            }
+        }
+
+        if enabled("serial") {
+            let serial_regex = Regex::new(r"(?i)\b(?:serial(?:[ _-]?(?:number|no))?|s/n|sn)\b[\s\x22']*[:=#]\s*[\x22']?([A-Z0-9][A-Z0-9-]{4,30}[A-Z0-9])\b").unwrap();
+            for cap in serial_regex.captures_iter(text) {
+                let m = cap.get(1).unwrap();
+                if m.as_str().chars().any(|c| c.is_ascii_digit()) {
+                    push(m, "SERIAL");
+                }
+            }
+        }
+
+        if enabled("mac") {
+            let mac_regex = Regex::new(MAC_PATTERN).unwrap();
+            for m in mac_regex.find_iter(text) {
+                // The bare twelve-hex form is also the last group of a UUID
+                if !text[..m.start()].ends_with('-') {
+                    push(m, "MAC");
+                }
+            }
+        }
+
+        self.redact_findings(text, findings, type_prefix, "device", config)
+    }
+
+    fn redact_vin(&mut self, text: &str, config: &serde_json::Value, type_prefix: &str) -> Result<String, JsValue> {
+        // The check digit is mandatory for North American VINs only; without it a "VIN" keyword is required
+        let require_check_digit = config.get("requireCheckDigit").and_then(|v| v.as_bool()).unwrap_or(true);
+
+        let vin_regex = Regex::new(r"\b[A-HJ-NPR-Z0-9]{17}\b").unwrap();
+        let findings = vin_regex.find_iter(text)
+            .filter(|m| is_valid_vin(m.as_str())
+                || (!require_check_digit
+                    && m.as_str().chars().any(|c| c.is_ascii_alphabetic())
+                    && preceded_by_keyword(text, m.start(), &["vin", "chassis"], 24)))
+            .map(|m| Finding::new(m.start(), m.end(), m.as_str()))
+            .collect();
+
+        self.redact_findings(text, findings, type_prefix, "vin", config)
+    }
+
+    fn redact_crypto_wallet(&mut self, text: &str, config: &serde_json::Value, type_prefix: &str) -> Result<String, JsValue> {
+        // Per-currency toggles; every supported currency is on unless a list is given
+        let currencies: Vec<String> = config.get("currencies")
+            .and_then(|v| v.as_array())
+            .map(|c| c.iter().filter_map(|v| v.as_str()).map(|s| s.to_uppercase()).collect())
+            .unwrap_or_else(|| ["BTC", "ETH", "LTC", "DOGE", "TRX"].iter().map(|s| s.to_string()).collect());
+        let enabled = |currency: &str| currencies.iter().any(|c| c == currency);
+
+        let mut findings = Vec::new();
+
+        // Base58Check: a version byte, a 20-byte hash and a four-byte double-SHA-256 checksum
+        let base58_regex = Regex::new(r"\b[13LMDT][1-9A-HJ-NP-Za-km-z]{25,34}\b").unwrap();
+        for m in base58_regex.find_iter(text) {
+            let Some(version) = base58check_version(m.as_str()) else {
+                continue;
+            };
+            let currency = match version {
+                0x00 | 0x05 => "BTC",
+                0x30 | 0x32 => "LTC",
+                0x1e | 0x16 => "DOGE",
+                0x41 => "TRX",
+                _ => continue,
+            };
+            if enabled(currency) {
+                findings.push(Finding::with_prefix(m.start(), m.end(), m.as_str(), format!("{}_{}", type_prefix, currency)));
+            }
+        }
+
+        // SegWit addresses in bech32/bech32m, checksum verified by the decoder
+        let bech32_regex = Regex::new(r"(?i)\b(?:bc|tb|ltc)1[02-9ac-hj-np-z]{11,87}\b").unwrap();
+        for m in bech32_regex.find_iter(text) {
+            let currency = if m.as_str().to_lowercase().starts_with("ltc") { "LTC" } else { "BTC" };
+            if enabled(currency) && bech32::segwit::decode(m.as_str()).is_ok() {
+                let finding = Finding {
+                    canonical: Some(m.as_str().to_lowercase()),
+                    ..Finding::with_prefix(m.start(), m.end(), m.as_str(), format!("{}_{}", type_prefix, currency))
+                };
+                findings.push(finding);
+            }
+        }
+
+        // Ethereum: single-case addresses carry no checksum, mixed case must satisfy EIP-55
+        if enabled("ETH") {
+            let eth_regex = Regex::new(r"\b0x[0-9a-fA-F]{40}\b").unwrap();
+            for m in eth_regex.find_iter(text) {
+                let hex_part = &m.as_str()[2..];
+                let single_case = hex_part == hex_part.to_lowercase() || hex_part == hex_part.to_uppercase();
+                if single_case || is_valid_eip55(hex_part) {
+                    let finding = Finding {
+                        canonical: Some(m.as_str().to_lowercase()),
+                        ..Finding::with_prefix(m.start(), m.end(), m.as_str(), format!("{}_ETH", type_prefix))
+                    };
+                    findings.push(finding);
+                }
+            }
+        }
+
+        self.redact_findings(text, findings, type_prefix, "crypto_wallet", config)
+    }
+
     fn redact_credit_card(&mut self, text: &str, _config: &serde_json::Value, type_prefix: &str) -> Result<String, JsValue> {
         let cc_regex = Regex::new(r"\b(?:\d{4}[\s-]?){3}\d{4,7}\b").unwrap();
         self.redact_with_regex(text, &cc_regex, type_prefix, "credit_card", _config)
     }
 
+    fn redact_iban(&mut self, text: &str, config: &serde_json::Value, type_prefix: &str) -> Result<String, JsValue> {
+        // Compact or printed in groups of four; validated by country length and mod-97 checksum
+        let iban_regex = Regex::new(r"\b[A-Z]{2}\d{2}(?: ?[A-Z0-9]{4}){2,7}(?: ?[A-Z0-9]{1,4})?\b").unwrap();
+
+        let mut findings = Vec::new();
+        for m in iban_regex.find_iter(text) {
+            // A printed IBAN can run into a following word ("... 0130 00 EUR"), so drop groups until it validates
+            let mut candidate = m.as_str();
+            loop {
+                if is_valid_iban(candidate) {
+                    findings.push(Finding::new(m.start(), m.start() + candidate.len(), candidate));
+                    break;
+                }
+                match candidate.rfind(' ') {
+                    Some(space) => candidate = &candidate[..space],
+                    None => break,
+                }
+            }
+        }
+
+        self.redact_findings(text, findings, type_prefix, "iban", config)
+    }
+
+    fn redact_bic(&mut self, text: &str, config: &serde_json::Value, type_prefix: &str) -> Result<String, JsValue> {
+        let require_context = config.get("requireContext").and_then(|v| v.as_bool()).unwrap_or(true);
+
+        // Bank (4) + country (2) + location (2) + optional branch (3)
+        let bic_regex = Regex::new(r"\b[A-Z]{4}([A-Z]{2})[A-Z0-9]{2}(?:[A-Z0-9]{3})?\b").unwrap();
+
+        let findings: Vec<Finding> = bic_regex.captures_iter(text)
+            .filter(|cap| is_country_code(&cap[1]))
+            .map(|cap| cap.get(0).unwrap())
+            // Eight capital letters are often just a shouted word, so look for a nearby keyword
+            .filter(|m| !require_context || preceded_by_keyword(text, m.start(), &["swift", "bic", "bank"], 16))
+            .map(|m| Finding::new(m.start(), m.end(), m.as_str()))
+            .collect();
+
+        self.redact_findings(text, findings, type_prefix, "bic", config)
+    }
+
+    fn redact_routing_number(&mut self, text: &str, config: &serde_json::Value, type_prefix: &str) -> Result<String, JsValue> {
+        let require_context = config.get("requireContext").and_then(|v| v.as_bool()).unwrap_or(true);
+        let routing_regex = Regex::new(r"\b\d{9}\b").unwrap();
+
+        let findings: Vec<Finding> = routing_regex.find_iter(text)
+            .filter(|m| is_valid_aba_routing(m.as_str()))
+            // One in ten random nine-digit numbers passes the checksum
+            .filter(|m| !require_context || preceded_by_keyword(text, m.start(), &["routing", "aba", "rtn", "transit", "bank"], 32))
+            .map(|m| Finding::new(m.start(), m.end(), m.as_str()))
+            .collect();
+
+        self.redact_findings(text, findings, type_prefix, "routing_number", config)
+    }
+
+    fn redact_bank_account(&mut self, text: &str, _config: &serde_json::Value, type_prefix: &str) -> Result<String, JsValue> {
+        // Account numbers have no structure of their own; only the labelled value is taken
+        let account_regex = Regex::new(r"(?i)\b(?:account|acct|acc|a/c)(?:[ _-]?(?:number|num|no|nr|#))?\.?\s*[:#=]?\s*(\d(?:[ -]?\d){5,16})\b").unwrap();
+        self.redact_captures(text, &account_regex, 1, type_prefix, "bank_account", _config)
+    }
+
     fn redact_api_key(&mut self, text: &str, config: &serde_json::Value, type_prefix: &str) -> Result<String, JsValue> {
         let regex = if let Some(prefix) = config.get("prefix").and_then(|v| v.as_str()) {
             if !prefix.is_empty() {
@@ -435,89 +1391,721 @@ impl Engine {
         self.redact_with_regex(text, &url_regex, type_prefix, "url", _config)
     }
 
-    fn redact_ipv6(&mut self, text: &str, _config: &serde_json::Value, type_prefix: &str) -> Result<String, JsValue> {
-        // Matches full and compressed IPv6 addresses
-        let ipv6_regex = Regex::new(r"(?i)\b(?:[0-9a-f]{1,4}:){7}[0-9a-f]{1,4}\b|\b(?:[0-9a-f]{1,4}:){1,7}:\b|\b(?:[0-9a-f]{1,4}:){1,6}:[0-9a-f]{1,4}\b|\b(?:[0-9a-f]{1,4}:){1,5}(?::[0-9a-f]{1,4}){1,2}\b|\b(?:[0-9a-f]{1,4}:){1,4}(?::[0-9a-f]{1,4}){1,3}\b|\b(?:[0-9a-f]{1,4}:){1,3}(?::[0-9a-f]{1,4}){1,4}\b|\b(?:[0-9a-f]{1,4}:){1,2}(?::[0-9a-f]{1,4}){1,5}\b|\b[0-9a-f]{1,4}:(?::[0-9a-f]{1,4}){1,6}\b|\b::(?:[0-9a-f]{1,4}:){0,6}[0-9a-f]{1,4}\b|\b(?:[0-9a-f]{1,4}:){1,7}:\b").unwrap();
-        self.redact_with_regex(text, &ipv6_regex, type_prefix, "ipv6", _config)
-    }
+    fn redact_connection_string(&mut self, text: &str, config: &serde_json::Value, type_prefix: &str) -> Result<String, JsValue> {
+        let redact_user = config.get("redactUser").and_then(|v| v.as_bool()).unwrap_or(false);
+        let redact_host = config.get("redactHost").and_then(|v| v.as_bool()).unwrap_or(true);
+        let params: Vec<String> = config.get("params")
+            .and_then(|v| v.as_array())
+            .map(|names| names.iter().filter_map(|v| v.as_str()).map(|s| s.to_lowercase()).collect::<Vec<_>>())
+            .filter(|names| !names.is_empty())
+            .unwrap_or_else(|| {
+                ["password", "pwd", "passwd", "secret", "token", "access_token", "api_key", "apikey", "key", "sig", "signature"]
+                    .iter()
+                    .map(|s| s.to_string())
+                    .collect()
+            });
+
+        // Any scheme, including chained JDBC schemes like jdbc:postgresql://
+        let uri_regex = Regex::new(r#"(?i)\b[a-z][a-z0-9+.-]*(?::[a-z][a-z0-9+.-]*)*://[^\s"'<>`]+"#).unwrap();
+        // Query parameters (?a=b&c=d) and JDBC properties (;user=sa;password=x)
+        let param_regex = Regex::new(r"[?&;]([^=&;#?/]+)=([^&;#]*)").unwrap();
+
+        let user_prefix = format!("{}_USER", type_prefix);
+        let password_prefix = format!("{}_PASSWORD", type_prefix);
+        let host_prefix = format!("{}_HOST", type_prefix);
+        let param_prefix = format!("{}_PARAM", type_prefix);
+
+        let mut findings = Vec::new();
+        for m in uri_regex.find_iter(text) {
+            let uri = m.as_str().trim_end_matches(['.', ',', ')']);
+            let rest_start = m.start() + uri.find("://").unwrap() + 3;
+            let rest = &text[rest_start..m.start() + uri.len()];
+            let authority_len = rest.find(['/', '?', '#', ';']).unwrap_or(rest.len());
+            let authority = &rest[..authority_len];
+
+            // Userinfo is everything before the last '@' of the authority
+            let hosts_offset = match authority.rfind('@') {
+                Some(at) => {
+                    let userinfo = &authority[..at];
+                    let (user, password) = match userinfo.find(':') {
+                        Some(colon) => (&userinfo[..colon], &userinfo[colon + 1..]),
+                        None => (userinfo, ""),
+                    };
+                    if redact_user && !user.is_empty() {
+                        findings.push(Finding::with_prefix(rest_start, rest_start + user.len(), user, user_prefix.clone()));
+                    }
+                    if !password.is_empty() {
+                        let start = rest_start + user.len() + 1;
+                        findings.push(Finding::with_prefix(start, start + password.len(), password, password_prefix.clone()));
+                    }
+                    at + 1
+                }
+                None => 0,
+            };
+
+            // Hosts, possibly a comma-separated replica list, without their ports
+            if redact_host {
+                let mut pos = rest_start + hosts_offset;
+                for host_port in authority[hosts_offset..].split(',') {
+                    let host_len = if host_port.starts_with('[') {
+                        host_port.find(']').map(|i| i + 1).unwrap_or(host_port.len())
+                    } else {
+                        host_port.find(':').unwrap_or(host_port.len())
+                    };
+                    if host_len > 0 {
+                        findings.push(Finding::with_prefix(pos, pos + host_len, &host_port[..host_len], host_prefix.clone()));
+                    }
+                    pos += host_port.len() + 1;
+                }
+            }
+
+            let tail_start = rest_start + authority_len;
+            for cap in param_regex.captures_iter(&rest[authority_len..]) {
+                let name = cap[1].to_lowercase();
+                let value = cap.get(2).unwrap();
+                if value.as_str().is_empty() {
+                    continue;
+                }
+
+                let prefix = if redact_user && matches!(name.as_str(), "user" | "username" | "uid") {
+                    &user_prefix
+                } else if params.contains(&name) && matches!(name.as_str(), "password" | "pwd" | "passwd") {
+                    &password_prefix
+                } else if params.contains(&name) {
+                    &param_prefix
+                } else {
+                    continue;
+                };
+                findings.push(Finding::with_prefix(tail_start + value.start(), tail_start + value.end(), value.as_str(), prefix.clone()));
+            }
+        }
+
+        self.redact_findings(text, findings, type_prefix, "connection_string", config)
+    }
+
+    fn redact_ipv6(&mut self, text: &str, config: &serde_json::Value, type_prefix: &str) -> Result<String, JsValue> {
+        // Full and compressed IPv6 candidates; the longest colon-hex run is validated as an address
+        // so compressed forms like 2001:db8::1 aren't cut short at the `::`
+        let ipv6_regex = Regex::new(r"(?i)(?:[0-9a-f]{0,4}:){2,7}[0-9a-f]{0,4}").unwrap();
+
+        let findings: Vec<Finding> = ipv6_regex.find_iter(text)
+            .filter(|m| {
+                let preceding = text[..m.start()].chars().next_back();
+                let following = text[m.end()..].chars().next();
+                !preceding.is_some_and(|c| c.is_alphanumeric() || c == ':')
+                    && !following.is_some_and(|c| c.is_alphanumeric())
+                    && m.as_str().chars().any(|c| c.is_ascii_hexdigit())
+                    && m.as_str().parse::<std::net::Ipv6Addr>().is_ok()
+            })
+            .map(|m| Finding::new(m.start(), m.end(), m.as_str()))
+            .collect();
+
+        let findings = self.prefix_preserving_findings(findings, "ipv6", config);
+        self.redact_findings(text, findings, type_prefix, "ipv6", config)
+    }
+
+    /// With `ipMode: "prefix_preserving"`, replaces each address with a Crypto-PAn style pseudonym:
+    /// addresses sharing their first n bits map to addresses sharing their first n bits, so subnet
+    /// relationships survive. The originals are still recorded in the map.
+    fn prefix_preserving_findings(&mut self, findings: Vec<Finding>, type_lower: &str, config: &serde_json::Value) -> Vec<Finding> {
+        if config.get("ipMode").and_then(|v| v.as_str()) != Some("prefix_preserving") {
+            return findings;
+        }
+
+        findings.into_iter()
+            .map(|mut finding| {
+                let address = normalize_value(type_lower, &finding.original)
+                    .and_then(|normalized| normalized.parse::<std::net::IpAddr>().ok());
+                let anonymized = match address {
+                    Some(std::net::IpAddr::V4(ip)) => std::net::Ipv4Addr::from(self.anonymize_ip_bits(u32::from(ip) as u128, 32) as u32).to_string(),
+                    Some(std::net::IpAddr::V6(ip)) => std::net::Ipv6Addr::from(self.anonymize_ip_bits(u128::from(ip), 128)).to_string(),
+                    None => return finding,
+                };
+                finding.replacement = Some(anonymized);
+                finding.record_original = true;
+                finding
+            })
+            .collect()
+    }
+
+    /// Flips each bit of a `width`-bit address by a keyed pseudorandom function of the bits before
+    /// it (HMAC-SHA256 with the session secret), the construction behind Crypto-PAn.
+    fn anonymize_ip_bits(&self, bits: u128, width: u32) -> u128 {
+        let mut anonymized = 0u128;
+        for i in 0..width {
+            let prefix = if i == 0 { 0 } else { bits >> (width - i) };
+            let mut mac = Hmac::<Sha256>::new_from_slice(&self.session_secret).unwrap();
+            mac.update(b"ip-prefix-preserving");
+            mac.update(&[width as u8, i as u8]);
+            mac.update(&prefix.to_be_bytes());
+            let flip = (mac.finalize().into_bytes()[0] >> 7) as u128;
+            let bit = (bits >> (width - 1 - i)) & 1;
+            anonymized = (anonymized << 1) | (bit ^ flip);
+        }
+        anonymized
+    }
 
     fn redact_mac(&mut self, text: &str, _config: &serde_json::Value, type_prefix: &str) -> Result<String, JsValue> {
-        // Matches MAC addresses in various formats: AA:BB:CC:DD:EE:FF, AA-BB-CC-DD-EE-FF, AABBCCDDEEFF
-        let mac_regex = Regex::new(r"(?i)\b(?:[0-9a-f]{2}[:-]){5}[0-9a-f]{2}\b|\b[0-9a-f]{12}\b").unwrap();
+        let mac_regex = Regex::new(MAC_PATTERN).unwrap();
         self.redact_with_regex(text, &mac_regex, type_prefix, "mac", _config)
     }
 
-    fn redact_hostname(&mut self, text: &str, _config: &serde_json::Value, type_prefix: &str) -> Result<String, JsValue> {
+    fn redact_hostname(&mut self, text: &str, config: &serde_json::Value, type_prefix: &str) -> Result<String, JsValue> {
+        // "full" redacts the whole name; "registrable" keeps the registrable domain (acme.co.uk)
+        // and redacts only the subdomains in front of it; "labels" also keeps the registrable domain
+        // but gives each subdomain label its own stable id, so siblings stay recognisable
+        let hostname_mode = config.get("hostnameMode").and_then(|v| v.as_str()).unwrap_or("full");
+        // Domains (and their subdomains) to leave untouched, e.g. vendor domains
+        let allowed_domains: Vec<String> = config.get("allowedDomains")
+            .and_then(|v| v.as_array())
+            .map(|d| d.iter().filter_map(|v| v.as_str()).map(|s| s.trim().trim_start_matches('.').to_lowercase()).filter(|s| !s.is_empty()).collect())
+            .unwrap_or_default();
+
         // Matches hostnames and FQDNs (but not URLs which are handled separately)
         let hostname_regex = Regex::new(r"\b(?:[a-zA-Z0-9](?:[a-zA-Z0-9-]{0,61}[a-zA-Z0-9])?\.)+[a-zA-Z]{2,}\b").unwrap();
-        self.redact_with_regex(text, &hostname_regex, type_prefix, "hostname", _config)
+        let suffixes = PublicSuffixList::new();
+        let label_prefix = format!("{}_LABEL", type_prefix);
+
+        let mut findings = Vec::new();
+        for m in hostname_regex.find_iter(text) {
+            let host = m.as_str().to_lowercase();
+            let labels: Vec<&str> = host.split('.').collect();
+            let Some(suffix_labels) = suffixes.suffix_len(&labels) else {
+                continue;
+            };
+            if labels.len() <= suffix_labels || is_code_reference(text, m.start(), m.end(), &labels) {
+                continue;
+            }
+            if allowed_domains.iter().any(|d| host == *d || host.ends_with(&format!(".{}", d))) {
+                continue;
+            }
+
+            if hostname_mode == "registrable" || hostname_mode == "labels" {
+                // Everything left of the registrable domain (one label plus the public suffix)
+                let registrable_len: usize = labels[labels.len() - suffix_labels - 1..].iter().map(|l| l.len() + 1).sum::<usize>() - 1;
+                let subdomain_len = m.len().saturating_sub(registrable_len + 1);
+                if subdomain_len == 0 {
+                    continue;
+                }
+                let subdomain = &m.as_str()[..subdomain_len];
+                if hostname_mode == "registrable" {
+                    findings.push(Finding::new(m.start(), m.start() + subdomain_len, subdomain));
+                    continue;
+                }
+
+                let mut offset = m.start();
+                let mut pseudonymized = Vec::new();
+                for label in subdomain.split('.') {
+                    let part = Finding::with_prefix(offset, offset + label.len(), label, label_prefix.clone());
+                    let id = self.record_finding(text, &part, type_prefix, "hostname");
+                    pseudonymized.push(self.apply_redaction_mode(label, &id, config));
+                    offset += label.len() + 1;
+                }
+                findings.push(Finding {
+                    replacement: Some(pseudonymized.join(".")),
+                    ..Finding::new(m.start(), m.start() + subdomain_len, subdomain)
+                });
+            } else {
+                findings.push(Finding::new(m.start(), m.end(), m.as_str()));
+            }
+        }
+
+        self.redact_findings(text, findings, type_prefix, "hostname", config)
     }
 
-    fn redact_username(&mut self, text: &str, _config: &serde_json::Value, type_prefix: &str) -> Result<String, JsValue> {
-        // Heuristic: matches common username patterns like @username, user=, username:, /home/username
-        let username_regex = Regex::new(r"(?:@|user=|username=|/home/|/users/)([a-zA-Z0-9_-]{3,32})\b").unwrap();
-        
-        let mut result = text.to_string();
-        let mut offset: i32 = 0;
-        
-        let matches: Vec<(usize, usize, String)> = username_regex.captures_iter(text)
-            .filter_map(|cap| {
-                cap.get(1).and_then(|m| {
-                    let start = m.start();
-                    let end = m.end();
-                    if self.is_region_claimed(start, end) {
-                        None
+    fn redact_path(&mut self, text: &str, config: &serde_json::Value, type_prefix: &str) -> Result<String, JsValue> {
+        let redact_user = config.get("redactUser").and_then(|v| v.as_bool()).unwrap_or(true);
+        // Further directory names to hide (project names, customer folders), with `*` wildcards
+        let segment_patterns: Vec<Regex> = config.get("segments")
+            .and_then(|v| v.as_array())
+            .map(|names| {
+                names.iter()
+                    .filter_map(|v| v.as_str())
+                    .filter(|p| !p.is_empty())
+                    .filter_map(|p| Regex::new(&format!("(?i)^{}$", regex::escape(p).replace(r"\*", ".*"))).ok())
+                    .collect()
+            })
+            .unwrap_or_default();
+
+        // Windows drive paths, UNC shares, and Unix paths including ~user. Windows directory names may
+        // contain spaces (C:\Users\John Smith\...) as long as another separator follows them
+        let path_regex = Regex::new(r#"(?i)\b[a-z]:[\\/](?:[^\s"'<>|*?\\/]+(?: +[^\s"'<>|*?\\/]+)*[\\/])*[^\s"'<>|*?]*|\\\\[a-z0-9._$-]+\\(?:[^\s"'<>|*?\\]+(?: +[^\s"'<>|*?\\]+)*\\)*[^\s"'<>|*?\\]+\\?|~[a-z0-9._-]+(?:/[a-z0-9._@%+~-]+)*/?|~?(?:/[a-z0-9._@%+~-]+)+/?"#).unwrap();
+        let user_prefix = format!("{}_USER", type_prefix);
+        let segment_prefix = format!("{}_SEGMENT", type_prefix);
+
+        let mut findings = Vec::new();
+        for m in path_regex.find_iter(text) {
+            // Skip the path part of URLs (scheme://host/path) and fragments of longer tokens
+            let before = text[..m.start()].chars().next_back();
+            if before.is_some_and(|c| c.is_alphanumeric() || matches!(c, ':' | '/' | '.' | '\\')) {
+                continue;
+            }
+            let path = m.as_str().trim_end_matches(['.', ',', ';', ')']);
+            let start = m.start();
+
+            // Byte ranges of every segment between separators
+            let mut segments: Vec<(usize, usize)> = Vec::new();
+            let mut segment_start = 0;
+            for (i, c) in path.char_indices().chain(std::iter::once((path.len(), '/'))) {
+                if c == '/' || c == '\\' {
+                    if i > segment_start {
+                        segments.push((segment_start, i));
+                    }
+                    segment_start = i + 1;
+                }
+            }
+
+            // The user is the segment after home/Users (or the name in ~user); shared profiles are not users
+            let user_index = if path.starts_with('~') && path.len() > 1 && !path[1..].starts_with('/') {
+                Some(0)
+            } else {
+                segments.iter()
+                    .take(4)
+                    .position(|&(s, e)| matches!(path[s..e].to_lowercase().as_str(), "home" | "users"))
+                    .map(|i| i + 1)
+                    .filter(|&i| i < segments.len())
+                    .filter(|&i| {
+                        let (s, e) = segments[i];
+                        !matches!(path[s..e].to_lowercase().as_str(), "public" | "default" | "shared" | "all users" | "default user")
+                    })
+            };
+
+            let mut redacted = path.to_string();
+            let mut changed = false;
+            for (index, &(seg_start, seg_end)) in segments.iter().enumerate().rev() {
+                let mut segment = &path[seg_start..seg_end];
+                let mut seg_start = seg_start;
+                let prefix = if Some(index) == user_index {
+                    if !redact_user {
+                        continue;
+                    }
+                    if let Some(name) = segment.strip_prefix('~') {
+                        segment = name;
+                        seg_start += 1;
+                    }
+                    user_prefix.clone()
+                } else if segment_patterns.iter().any(|p| p.is_match(segment)) {
+                    segment_prefix.clone()
+                } else {
+                    continue;
+                };
+
+                let part = Finding::with_prefix(start + seg_start, start + seg_end, segment, prefix);
+                let id = self.record_finding(text, &part, type_prefix, "path");
+                let replacement = self.apply_redaction_mode(segment, &id, config);
+                redacted.replace_range(seg_start..seg_end, &replacement);
+                changed = true;
+            }
+
+            if changed {
+                // The full path is kept in the map so the original location can be looked up
+                findings.push(Finding {
+                    replacement: Some(redacted),
+                    record_original: true,
+                    ..Finding::new(start, start + path.len(), path)
+                });
+            }
+        }
+
+        self.redact_findings(text, findings, type_prefix, "path", config)
+    }
+
+    fn redact_windows_identity(&mut self, text: &str, config: &serde_json::Value, type_prefix: &str) -> Result<String, JsValue> {
+        // Per-identifier toggles; everything is on unless a list is given
+        let identifiers: Vec<String> = config.get("identifiers")
+            .and_then(|v| v.as_array())
+            .map(|c| c.iter().filter_map(|v| v.as_str()).map(|s| s.to_lowercase()).collect())
+            .unwrap_or_else(|| ["sid", "account", "upn", "dn", "machine"].iter().map(|s| s.to_string()).collect());
+        let enabled = |id: &str| identifiers.iter().any(|i| i == id);
+        // Domain names stay visible by default so the directory structure can still be followed
+        let redact_domain = config.get("redactDomain").and_then(|v| v.as_bool()).unwrap_or(false);
+
+        let mut findings = Vec::new();
+        let mut push = |start: usize, value: &str, kind: &str| {
+            findings.push(Finding::with_prefix(start, start + value.len(), value, format!("{}_{}", type_prefix, kind)));
+        };
+
+        if enabled("sid") {
+            // Only domain (S-1-5-21-…) and Entra ID (S-1-12-1-…) SIDs identify anything; the relative id
+            // at the end stays, so well-known RIDs like 500 or 512 remain readable
+            let sid_regex = Regex::new(r"\bS-1-(?:5-21|12-1)-(\d+-\d+-\d+)(?:-(\d+))?\b").unwrap();
+            for cap in sid_regex.captures_iter(text) {
+                let m = cap.get(1).unwrap();
+                push(m.start(), m.as_str(), "SID");
+            }
+        }
+
+        if enabled("account") || enabled("machine") {
+            // DOMAIN\user (or DOMAIN\\user when JSON-escaped); MACHINE$ accounts are computers
+            let account_regex = Regex::new(r"\b([A-Z][A-Z0-9-]{0,14})(\\{1,2})([A-Za-z0-9._-]{2,64}\$?)").unwrap();
+            for cap in account_regex.captures_iter(text) {
+                let (domain, user) = (cap.get(1).unwrap(), cap.get(3).unwrap());
+                if text[..domain.start()].ends_with(['\\', ':', '/'])
+                    || WELL_KNOWN_WINDOWS_DOMAINS.contains(&domain.as_str())
+                    || REGISTRY_HIVES.contains(&domain.as_str())
+                    || domain.as_str().starts_with("HKEY")
+                {
+                    continue;
+                }
+                // A further \segment makes it a path (registry key, share), not an account
+                if text[user.end()..].starts_with('\\') {
+                    continue;
+                }
+                // Inside a JSON string a lone backslash is an escape ("FAILED\tretrying"); accounts are doubled there
+                if cap[2].len() == 1 && user.as_str().starts_with(['n', 'r', 't', 'b', 'f', 'u']) && in_json_string(text, domain.start()) {
+                    continue;
+                }
+                let is_machine = user.as_str().ends_with('$');
+                if is_machine && enabled("machine") {
+                    let name = user.as_str().trim_end_matches('$');
+                    push(user.start(), name, "HOST");
+                } else if !is_machine && enabled("account") && !WELL_KNOWN_WINDOWS_ACCOUNTS.contains(&user.as_str().to_uppercase().as_str()) {
+                    push(user.start(), user.as_str(), "USER");
+                } else {
+                    continue;
+                }
+                if redact_domain {
+                    push(domain.start(), domain.as_str(), "DOMAIN");
+                }
+            }
+        }
+
+        if enabled("upn") {
+            // UPNs look like email addresses, so require a UPN keyword or a directory-style suffix
+            let upn_regex = Regex::new(r"(?i)\b([a-z0-9._'-]+)@((?:[a-z0-9-]+\.)+[a-z]{2,})\b").unwrap();
+            for cap in upn_regex.captures_iter(text) {
+                let (user, domain) = (cap.get(1).unwrap(), cap.get(2).unwrap());
+                let directory_suffix = [".local", ".corp", ".ad", ".internal", ".lan"].iter().any(|s| domain.as_str().to_lowercase().ends_with(s));
+                if directory_suffix || preceded_by_keyword(text, user.start(), &["upn", "userprincipalname", "principal"], 32) {
+                    push(user.start(), user.as_str(), "USER");
+                    if redact_domain {
+                        push(domain.start(), domain.as_str(), "DOMAIN");
+                    }
+                }
+            }
+        }
+
+        if enabled("dn") {
+            // CN and UID values name users and computers; OU and DC describe the directory layout.
+            // DC components are DNS labels, which keeps a DN from running into the following prose
+            let rdn = r"(?:DC=[a-z0-9-]+|(?:CN|UID|OU|O|L|ST|C)=(?:\\.|[^,\\\r\n\x22])+)";
+            let dn_regex = Regex::new(&format!(r"(?i)\b{}(?:\s*,\s*{})+", rdn, rdn)).unwrap();
+            let rdn_regex = Regex::new(r"(?i)\b(DC)=([a-z0-9-]+)|\b(CN|UID|OU|O|L|ST|C)=((?:\\.|[^,\\\r\n\x22])+)").unwrap();
+            for dn in dn_regex.find_iter(text) {
+                for rdn in rdn_regex.captures_iter(dn.as_str()) {
+                    let attribute = rdn.get(1).or_else(|| rdn.get(3)).unwrap().as_str().to_uppercase();
+                    let value = rdn.get(2).or_else(|| rdn.get(4)).unwrap();
+                    let name = value.as_str().trim_end();
+                    let kind = match attribute.as_str() {
+                        "CN" if WELL_KNOWN_AD_CONTAINERS.iter().any(|c| c.eq_ignore_ascii_case(name)) => continue,
+                        "CN" | "UID" => "CN",
+                        "DC" if redact_domain => "DOMAIN",
+                        _ => continue,
+                    };
+                    push(dn.start() + value.start(), name, kind);
+                }
+            }
+        }
+
+        if enabled("machine") {
+            let machine_regex = Regex::new(r#"(?i)\b(?:computer\s?name|computer|workstation\s?name|workstation|machine\s?name|client\s?name)["']?\s*[:=]\s*["']?([a-z0-9][a-z0-9-]{0,14})\b"#).unwrap();
+            for cap in machine_regex.captures_iter(text) {
+                let m = cap.get(1).unwrap();
+                push(m.start(), m.as_str(), "HOST");
+            }
+        }
+
+        self.redact_findings(text, findings, type_prefix, "windows_identity", config)
+    }
+
+    fn redact_cloud_resource(&mut self, text: &str, config: &serde_json::Value, type_prefix: &str) -> Result<String, JsValue> {
+        // Account, subscription, tenant and project ids are always redacted; regions and resource
+        // names are selectable so the layout can be kept when it is not sensitive
+        let redact_region = config.get("redactRegion").and_then(|v| v.as_bool()).unwrap_or(false);
+        let redact_names = config.get("redactResourceNames").and_then(|v| v.as_bool()).unwrap_or(true);
+
+        let mut findings = Vec::new();
+        let mut push = |start: usize, value: &str, kind: &str| {
+            if !value.is_empty() {
+                findings.push(Finding::with_prefix(start, start + value.len(), value, format!("{}_{}", type_prefix, kind)));
+            }
+        };
+
+        // arn:partition:service:region:account:resource, where resource is type/name, type:name or name
+        let arn_regex = Regex::new(r#"\barn:aws(?:-cn|-us-gov)?:([a-z0-9-]+):([a-z0-9-]*):(\d{12})?:([^\s"'<>,]+)"#).unwrap();
+        for cap in arn_regex.captures_iter(text) {
+            let service = &cap[1];
+            if let Some(region) = cap.get(2) && redact_region {
+                push(region.start(), region.as_str(), "REGION");
+            }
+            if let Some(account) = cap.get(3) {
+                push(account.start(), account.as_str(), "ACCOUNT");
+            }
+            if !redact_names {
+                continue;
+            }
+            let resource = cap.get(4).unwrap();
+            let (kind, name_offset) = match resource.as_str().find(['/', ':']) {
+                // S3 ARNs have no type: the bucket comes first and the object key is left alone
+                _ if service == "s3" => ("BUCKET".to_string(), 0),
+                Some(separator) => (cloud_resource_kind(&resource.as_str()[..separator]), separator + 1),
+                None => ("RESOURCE".to_string(), 0),
+            };
+            let mut name = &resource.as_str()[name_offset..];
+            if service == "s3" {
+                name = name.split('/').next().unwrap();
+            }
+            push(resource.start() + name_offset, name, &kind);
+        }
+
+        // Bare account ids need a keyword; ECR registry hosts start with one
+        let account_regex = Regex::new(r"\b\d{12}\b").unwrap();
+        for m in account_regex.find_iter(text) {
+            let keyword = preceded_by_keyword(text, m.start(), &["account", "owner", "aws_account", "accountid"], 24);
+            if keyword || text[m.end()..].starts_with(".dkr.ecr.") {
+                push(m.start(), m.as_str(), "ACCOUNT");
+            }
+        }
+
+        // Azure: /subscriptions/{id}/resourceGroups/{name}/providers/{namespace}/{type}/{name}/...
+        let azure_regex = Regex::new(r#"(?i)/subscriptions/([0-9a-f]{8}-[0-9a-f]{4}-[0-9a-f]{4}-[0-9a-f]{4}-[0-9a-f]{12})(?:/resourceGroups/([^/\s"']+))?(?:/providers/[A-Za-z0-9.]+((?:/[^/\s"']+/[^/\s"']+)+))?"#).unwrap();
+        for cap in azure_regex.captures_iter(text) {
+            let subscription = cap.get(1).unwrap();
+            push(subscription.start(), subscription.as_str(), "SUBSCRIPTION");
+            if !redact_names {
+                continue;
+            }
+            if let Some(group) = cap.get(2) {
+                push(group.start(), group.as_str(), "RESOURCE_GROUP");
+            }
+            if let Some(path) = cap.get(3) {
+                // Alternating type/name segments; the type names the component
+                let mut offset = path.start();
+                let mut kind = String::new();
+                for (i, segment) in path.as_str()[1..].split('/').enumerate() {
+                    offset += 1;
+                    if i % 2 == 0 {
+                        kind = cloud_resource_kind(segment);
                     } else {
-                        Some((start, end, m.as_str().to_string()))
+                        push(offset, segment, &kind);
                     }
-                })
+                    offset += segment.len();
+                }
+            }
+        }
+
+        // Azure tenants: login URLs, /tenants/{id} paths and tenant id keywords
+        let tenant_regex = Regex::new(r#"(?i)(?:login\.microsoftonline\.com/|/tenants/|tenant[_ -]?id["']?\s*[:=]\s*["']?)([0-9a-f]{8}-[0-9a-f]{4}-[0-9a-f]{4}-[0-9a-f]{4}-[0-9a-f]{12})"#).unwrap();
+        for cap in tenant_regex.captures_iter(text) {
+            let m = cap.get(1).unwrap();
+            push(m.start(), m.as_str(), "TENANT");
+        }
+
+        // GCP: projects/{id} resource names, project_id keys, --project flags and service account domains
+        let gcp_regex = Regex::new(r#"(?:\bprojects/|\bproject(?:_id|Id)?["']?\s*[:=]\s*["']?|--project[= ]|@)([a-z][a-z0-9-]{4,28}[a-z0-9])(?:\.iam\.gserviceaccount\.com)?\b"#).unwrap();
+        for cap in gcp_regex.captures_iter(text) {
+            let whole = cap.get(0).unwrap();
+            let m = cap.get(1).unwrap();
+            // A bare @ is only a project inside a service account address
+            if whole.as_str().starts_with('@') && !whole.as_str().ends_with(".iam.gserviceaccount.com") {
+                continue;
+            }
+            push(m.start(), m.as_str(), "PROJECT");
+        }
+
+        self.redact_findings(text, findings, type_prefix, "cloud_resource", config)
+    }
+
+    fn redact_kubernetes(&mut self, text: &str, config: &serde_json::Value, type_prefix: &str) -> Result<String, JsValue> {
+        let include_config_maps = config.get("configMaps").and_then(|v| v.as_bool()).unwrap_or(true);
+        let redact_nodes = config.get("redactNodes").and_then(|v| v.as_bool()).unwrap_or(false);
+        let redact_namespaces = config.get("redactNamespaces").and_then(|v| v.as_bool()).unwrap_or(false);
+        let redact_pod_ips = config.get("redactPodIps").and_then(|v| v.as_bool()).unwrap_or(false);
+
+        let kind_prefix = |kind: &str| match kind {
+            "Secret" => Some(format!("{}_SECRET", type_prefix)),
+            "ConfigMap" if include_config_maps => Some(format!("{}_CONFIG", type_prefix)),
+            _ => None,
+        };
+
+        let mut findings = Vec::new();
+        // data and binaryData hold base64; the decoded value is fingerprinted so the same secret
+        // matches whether it appears encoded, in stringData or anywhere else
+        let mut push_value = |start: usize, value: &str, field: &str, prefix: String| {
+            if value.is_empty() {
+                return;
+            }
+            let decoded = (field != "stringData")
+                .then(|| base64::engine::general_purpose::STANDARD.decode(value.trim()).ok())
+                .flatten()
+                .map(|bytes| String::from_utf8_lossy(&bytes).into_owned());
+            findings.push(Finding {
+                canonical: decoded,
+                ..Finding::with_prefix(start, start + value.len(), value, prefix)
+            });
+        };
+
+        // YAML manifests (kubectl get -o yaml, including List items)
+        let lines: Vec<(usize, &str)> = text.split_inclusive('\n')
+            .scan(0, |offset, line| {
+                let start = *offset;
+                *offset += line.len();
+                Some((start, line.trim_end_matches(['\r', '\n'])))
             })
             .collect();
-
-        for (start, end, original) in matches {
-            let fingerprint = self.generate_fingerprint(&original);
-            
-            // Capture context
-            let context_start = if start >= 20 { start - 20 } else { 0 };
-            let context_end = if end + 20 <= text.len() { end + 20 } else { text.len() };
-            let context = text[context_start..context_end].to_string();
-            
-            let entry = self.canonical_map.entry(fingerprint.clone()).or_insert_with(|| {
-                let r_type = type_prefix.to_string();
-                let count = self.next_ids.entry(r_type.clone()).or_insert(1);
-                let id = format!("{}_{}", r_type, count);
-                *count += 1;
-                
-                CanonicalEntry {
-                    id,
-                    r#type: "username".to_string(),
-                    original: original.clone(),
-                    fingerprint: fingerprint.clone(),
-                    occurrences: 0,
-                    contexts: vec![],
+        let field_regex = Regex::new(r"^(\s*(?:-\s+)?)(data|stringData|binaryData):\s*(?:#.*)?$").unwrap();
+        let entry_regex = Regex::new(r#"^(\s*)(?:"[^"]*"|'[^']*'|[^\s:#][^:#]*):\s+(?:"([^"]*)"|'([^']*)'|(\S.*?))\s*$"#).unwrap();
+        for (index, &(_, line)) in lines.iter().enumerate() {
+            let Some(cap) = field_regex.captures(line) else {
+                continue;
+            };
+            let indent = cap[1].len();
+            let field = cap[2].to_string();
+            let Some(prefix) = yaml_object_kind(&lines, index, indent).and_then(|kind| kind_prefix(&kind)) else {
+                continue;
+            };
+
+            let mut child = index + 1;
+            let mut child_indent = None;
+            while child < lines.len() {
+                let (offset, child_line) = lines[child];
+                let depth = yaml_indent(child_line);
+                if child_line.trim().is_empty() || child_line.trim_start().starts_with('#') {
+                    child += 1;
+                    continue;
                 }
-            });
+                if depth <= indent || child_indent.is_some_and(|c| depth < c) {
+                    break;
+                }
+                let entry_indent = *child_indent.get_or_insert(depth);
+                child += 1;
+                if depth > entry_indent {
+                    continue;
+                }
+                let Some(entry) = entry_regex.captures(child_line) else {
+                    continue;
+                };
+                let value = entry.get(2).or_else(|| entry.get(3)).or_else(|| entry.get(4)).unwrap();
+                if matches!(value.as_str().chars().next(), Some('|' | '>')) {
+                    // Block scalar: everything indented below the key is the value
+                    let mut last = child;
+                    while last < lines.len() && (lines[last].1.trim().is_empty() || yaml_indent(lines[last].1) > entry_indent) {
+                        last += 1;
+                    }
+                    while last > child && lines[last - 1].1.trim().is_empty() {
+                        last -= 1;
+                    }
+                    if last > child {
+                        let block_start = lines[child].0 + yaml_indent(lines[child].1);
+                        let block_end = lines[last - 1].0 + lines[last - 1].1.len();
+                        push_value(block_start, &text[block_start..block_end], &field, prefix.clone());
+                    }
+                    child = last;
+                } else {
+                    push_value(offset + value.start(), value.as_str(), &field, prefix.clone());
+                }
+            }
+        }
 
-            entry.occurrences += 1;
-            
-            if entry.contexts.len() < 3 && !entry.contexts.contains(&context) {
-                entry.contexts.push(context);
+        // JSON manifests (kubectl get -o json, and last-applied-configuration annotations in YAML)
+        let pairs = json_brace_pairs(text);
+        let json_field_regex = Regex::new(r#""(data|stringData|binaryData)"\s*:\s*\{"#).unwrap();
+        let json_kind_regex = Regex::new(r#""kind"\s*:\s*"(Secret|ConfigMap)""#).unwrap();
+        let json_entry_regex = Regex::new(r#""(?:[^"\\]|\\.)*"\s*:\s*"((?:[^"\\]|\\.)*)""#).unwrap();
+        for m in json_field_regex.captures_iter(text) {
+            let whole = m.get(0).unwrap();
+            let open = whole.end() - 1;
+            let Some(&(_, close)) = pairs.iter().find(|(o, _)| *o == open) else {
+                continue;
+            };
+            // The innermost object around the field decides whether it is a Secret or ConfigMap
+            let Some(&(object_start, object_end)) = pairs.iter()
+                .filter(|(o, c)| *o < whole.start() && *c > close)
+                .max_by_key(|(o, _)| *o)
+            else {
+                continue;
+            };
+            let object = &text[object_start..object_end];
+            let Some(prefix) = json_kind_regex.captures(object).and_then(|k| kind_prefix(&k[1])) else {
+                continue;
+            };
+            for entry in json_entry_regex.captures_iter(&text[open..close]) {
+                let value = entry.get(1).unwrap();
+                push_value(open + value.start(), value.as_str(), &m[1], prefix.clone());
             }
-            
-            let replacement = format!("<{}>", entry.id);
-            let actual_start = (start as i32 + offset) as usize;
-            let actual_end = (end as i32 + offset) as usize;
-            
-            result.replace_range(actual_start..actual_end, &replacement);
-            offset += replacement.len() as i32 - (end - start) as i32;
-            
-            self.claimed_regions.push(ClaimedRegion {
-                start,
-                end,
-            });
         }
 
-        Ok(result)
+        // Service account tokens outside Secrets: token fields holding a JWT
+        let token_regex = Regex::new(r#"\btoken["']?\s*[:=]\s*["']?(eyJ[A-Za-z0-9_-]+\.[A-Za-z0-9_-]+\.[A-Za-z0-9_-]*)"#).unwrap();
+        for cap in token_regex.captures_iter(text) {
+            let m = cap.get(1).unwrap();
+            findings.push(Finding::with_prefix(m.start(), m.end(), m.as_str(), format!("{}_TOKEN", type_prefix)));
+        }
+
+        let mut push_field = |pattern: &str, kind: &str| {
+            let regex = Regex::new(pattern).unwrap();
+            for cap in regex.captures_iter(text) {
+                let m = cap.get(1).unwrap();
+                findings.push(Finding::with_prefix(m.start(), m.end(), m.as_str(), format!("{}_{}", type_prefix, kind)));
+            }
+        };
+        if redact_nodes {
+            push_field(r#"\b(?:nodeName|kubernetes\.io/hostname)["']?\s*:\s*["']?([a-z0-9][a-z0-9.-]*[a-z0-9])"#, "NODE");
+            push_field(r#"\bhostIPs?["']?\s*:\s*["']?(\d{1,3}(?:\.\d{1,3}){3})"#, "NODE_IP");
+        }
+        if redact_namespaces {
+            // Built-in namespaces say nothing about the cluster owner
+            let namespace_regex = Regex::new(r#"(?:\bnamespace["']?\s*:\s*["']?|--namespace[= ]|\s-n\s+)([a-z0-9][a-z0-9-]*[a-z0-9])"#).unwrap();
+            for cap in namespace_regex.captures_iter(text) {
+                let m = cap.get(1).unwrap();
+                if !matches!(m.as_str(), "default" | "kube-system" | "kube-public" | "kube-node-lease") {
+                    findings.push(Finding::with_prefix(m.start(), m.end(), m.as_str(), format!("{}_NAMESPACE", type_prefix)));
+                }
+            }
+        }
+        if redact_pod_ips {
+            let pod_ip_regex = Regex::new(r#"\b(?:podIP|ip)["']?\s*:\s*["']?(\d{1,3}(?:\.\d{1,3}){3}|[0-9a-fA-F:]*:[0-9a-fA-F:]+)"#).unwrap();
+            for cap in pod_ip_regex.captures_iter(text) {
+                let m = cap.get(1).unwrap();
+                // Bare ip: keys only count inside a podIPs list
+                if cap[0].starts_with("podIP") || preceded_by_keyword(text, m.start(), &["podips"], 48) {
+                    findings.push(Finding::with_prefix(m.start(), m.end(), m.as_str(), format!("{}_POD_IP", type_prefix)));
+                }
+            }
+        }
+
+        self.redact_findings(text, findings, type_prefix, "kubernetes", config)
+    }
+
+    fn redact_username(&mut self, text: &str, config: &serde_json::Value, type_prefix: &str) -> Result<String, JsValue> {
+        // Text that introduces a username, e.g. @username, user=, owner:, /home/username
+        let triggers: Vec<String> = config.get("triggers")
+            .and_then(|v| v.as_array())
+            .map(|t| t.iter().filter_map(|v| v.as_str()).map(|s| s.trim().to_string()).filter(|s| !s.is_empty()).collect::<Vec<_>>())
+            .filter(|t| !t.is_empty())
+            .unwrap_or_else(|| {
+                ["@", "user=", "username=", "login=", "uid=", "owner:", "/home/", "/users/"].iter().map(|s| s.to_string()).collect()
+            });
+
+        let triggers_pattern = triggers.iter()
+            .map(|trigger| {
+                let escaped = regex::escape(trigger);
+                // Keywords start on a word boundary; a bare @ must not be the middle of an email address
+                let lead = match trigger.chars().next() {
+                    Some('@') => r"(?:^|[^\w.+-])",
+                    Some(c) if c.is_alphanumeric() => r"\b",
+                    _ => "",
+                };
+                let gap = if trigger.ends_with([':', '=']) { r#"\s*["']?"# } else { "" };
+                format!("{}{}{}", lead, escaped, gap)
+            })
+            .collect::<Vec<_>>()
+            .join("|");
+
+        let pattern = format!(r"(?im)(?:{})([a-z0-9_-](?:[a-z0-9_.-]{{1,30}}[a-z0-9_-]))\b", triggers_pattern);
+        let username_regex = Regex::new(&pattern).map_err(|e| JsValue::from_str(&format!("Invalid username trigger: {}", e)))?;
+
+        self.redact_captures(text, &username_regex, 1, type_prefix, "username", config)
     }
 
     fn redact_base64(&mut self, text: &str, _config: &serde_json::Value, type_prefix: &str) -> Result<String, JsValue> {
@@ -527,57 +2115,108 @@ impl Engine {
         self.redact_with_regex(text, &base64_regex, type_prefix, "base64", _config)
     }
 
+    fn redact_entropy(&mut self, text: &str, config: &serde_json::Value, type_prefix: &str) -> Result<String, JsValue> {
+        let min_length = config.get("minLength").and_then(|v| v.as_u64()).unwrap_or(20) as usize;
+        let hex_threshold = config.get("hexThreshold").and_then(|v| v.as_f64()).unwrap_or(3.0);
+        let base64_threshold = config.get("base64Threshold").and_then(|v| v.as_f64()).unwrap_or(4.0);
+        let ascii_threshold = config.get("asciiThreshold").and_then(|v| v.as_f64()).unwrap_or(4.0);
+
+        // Candidate tokens: quoted values, assignment right-hand sides and long hex/base64 runs
+        let quoted_regex = Regex::new(r#""([^"\s]+)"|'([^'\s]+)'"#).unwrap();
+        let assignment_regex = Regex::new(r#"[=:]\s*([^\s"',;&)\]}>]+)"#).unwrap();
+        let run_regex = Regex::new(r"[A-Za-z0-9+/_-]+=*").unwrap();
+        let uuid_regex = Regex::new(r"^[0-9a-fA-F]{8}-[0-9a-fA-F]{4}-[0-9a-fA-F]{4}-[0-9a-fA-F]{4}-[0-9a-fA-F]{12}$").unwrap();
+
+        let mut candidates: Vec<(usize, usize)> = Vec::new();
+        for cap in quoted_regex.captures_iter(text) {
+            if let Some(m) = cap.get(1).or_else(|| cap.get(2)) {
+                candidates.push((m.start(), m.end()));
+            }
+        }
+        for cap in assignment_regex.captures_iter(text) {
+            if let Some(m) = cap.get(1) {
+                candidates.push((m.start(), m.end()));
+            }
+        }
+        for m in run_regex.find_iter(text) {
+            candidates.push((m.start(), m.end()));
+        }
+
+        // Prefer the longest candidate at each position; overlaps are dropped when redacting
+        candidates.sort_by(|a, b| a.0.cmp(&b.0).then(b.1.cmp(&a.1)));
+        candidates.dedup();
+
+        let mut findings = Vec::new();
+        for (start, end) in candidates {
+            let value = &text[start..end];
+            if value.len() < min_length || uuid_regex.is_match(value) || is_known_hash_context(text, start, end) {
+                continue;
+            }
+
+            let threshold = if value.chars().all(|c| c.is_ascii_hexdigit()) {
+                hex_threshold
+            } else {
+                // Random tokens mix letters and digits; this filters out words, identifiers and paths
+                let has_digit = value.chars().any(|c| c.is_ascii_digit());
+                let has_alpha = value.chars().any(|c| c.is_ascii_alphabetic());
+                if !has_digit || !has_alpha || value.starts_with('/') || value.contains("://") {
+                    continue;
+                }
+                let is_base64 = value.trim_end_matches('=')
+                    .chars()
+                    .all(|c| c.is_ascii_alphanumeric() || matches!(c, '+' | '/' | '-' | '_'));
+                if is_base64 { base64_threshold } else { ascii_threshold }
+            };
+
+            let entropy = shannon_entropy(value);
+            if entropy >= threshold {
+                findings.push(Finding {
+                    entropy: Some((entropy * 1000.0).round() / 1000.0),
+                    ..Finding::new(start, end, value)
+                });
+            }
+        }
+
+        self.redact_findings(text, findings, type_prefix, "entropy", config)
+    }
+
     fn redact_with_regex(&mut self, text: &str, regex: &Regex, type_upper: &str, type_lower: &str, config: &serde_json::Value) -> Result<String, JsValue> {
+        let findings: Vec<Finding> = regex.find_iter(text)
+            .map(|m| Finding::new(m.start(), m.end(), m.as_str()))
+            .collect();
+
+        self.redact_findings(text, findings, type_upper, type_lower, config)
+    }
+
+    fn redact_findings(&mut self, text: &str, mut findings: Vec<Finding>, type_upper: &str, type_lower: &str, config: &serde_json::Value) -> Result<String, JsValue> {
         let mut result = text.to_string();
         let mut offset: i32 = 0; // Track offset due to replacements
-        
-        let matches: Vec<(usize, usize, String)> = regex.find_iter(text)
-            .filter_map(|m| {
-                let start = m.start();
-                let end = m.end();
-                // Check if this region overlaps with any claimed region
-                if self.is_region_claimed(start, end) {
-                    None
-                } else {
-                    Some((start, end, m.as_str().to_string()))
-                }
-            })
-            .collect();
 
-        for (start, end, original) in matches {
-            let fingerprint = self.generate_fingerprint(&original);
-            
-            // Capture context (20 chars before and after)
-            let context_start = if start >= 20 { start - 20 } else { 0 };
-            let context_end = if end + 20 <= text.len() { end + 20 } else { text.len() };
-            let context = text[context_start..context_end].to_string();
-            
-            let entry = self.canonical_map.entry(fingerprint.clone()).or_insert_with(|| {
-                let r_type = type_upper.to_string();
-                let count = self.next_ids.entry(r_type.clone()).or_insert(1);
-                let id = format!("{}_{}", r_type, count);
-                *count += 1;
-                
-                CanonicalEntry {
-                    id,
-                    r#type: type_lower.to_string(),
-                    original: original.clone(),
-                    fingerprint: fingerprint.clone(),
-                    occurrences: 0,
-                    contexts: vec![],
-                }
-            });
+        // Replacements are applied left to right so the running offset stays valid
+        findings.sort_by_key(|f| f.start);
 
-            entry.occurrences += 1;
-            
-            // Add context if not already present (limit to 3 contexts per entry)
-            if entry.contexts.len() < 3 && !entry.contexts.contains(&context) {
-                entry.contexts.push(context);
+        for finding in findings {
+            let (start, end) = (finding.start, finding.end);
+
+            // Skip anything overlapping a claimed region, including earlier findings from this batch
+            if self.is_region_claimed(start, end) {
+                continue;
             }
-            
-            // Clone the ID to avoid borrow checker issues
-            let canonical_id = entry.id.clone();
-            let replacement = self.apply_redaction_mode(&original, &canonical_id, config);
+
+            // Composite findings carry their own replacement and have already recorded their parts;
+            // generalized ones are only recorded here, once it is certain they are applied
+            let replacement = match &finding.replacement {
+                Some(replacement) => {
+                    if finding.record_original {
+                        self.record_finding(text, &finding, type_upper, type_lower);
+                    }
+                    replacement.clone()
+                }
+                None => {
+                    let canonical_id = self.record_finding(text, &finding, type_upper, type_lower);
+                    self.apply_redaction_mode(&finding.original, &canonical_id, config)
+                }
+            };
             
             // Calculate actual position in result string (accounting for previous replacements)
             let actual_start = (start as i32 + offset) as usize;
@@ -598,6 +2237,53 @@ impl Engine {
 
         Ok(result)
     }
+
+    /// Records a finding in the canonical map and returns its canonical id.
+    fn record_finding(&mut self, text: &str, finding: &Finding, type_upper: &str, type_lower: &str) -> String {
+        let Finding { start, end, original, .. } = finding;
+
+        // Formatting variants of the same value share one canonical id
+        let canonical = finding.canonical.clone().or_else(|| normalize_value(type_lower, original));
+        let fingerprint = self.generate_fingerprint(canonical.as_deref().unwrap_or(original));
+        
+        // Capture context (20 chars before and after)
+        let context_start = text.floor_char_boundary(start.saturating_sub(20));
+        let context_end = text.ceil_char_boundary((end + 20).min(text.len()));
+        let context = text[context_start..context_end].to_string();
+        
+        let entry = self.canonical_map.entry(fingerprint.clone()).or_insert_with(|| {
+            let r_type = finding.prefix.clone().unwrap_or_else(|| type_upper.to_string());
+            let count = self.next_ids.entry(r_type.clone()).or_insert(1);
+            let id = format!("{}_{}", r_type, count);
+            *count += 1;
+            
+            CanonicalEntry {
+                id,
+                r#type: type_lower.to_string(),
+                original: original.clone(),
+                fingerprint: fingerprint.clone(),
+                occurrences: 0,
+                contexts: vec![],
+                variants: vec![],
+                entropy: finding.entropy,
+                confidence: finding.confidence,
+            }
+        });
+
+        entry.occurrences += 1;
+
+        // Every distinct spelling seen for this value stays recoverable from the map
+        if !entry.variants.contains(original) {
+            entry.variants.push(original.clone());
+        }
+        
+        // Add context if not already present (limit to 3 contexts per entry)
+        if entry.contexts.len() < 3 && !entry.contexts.contains(&context) {
+            entry.contexts.push(context);
+        }
+
+        entry.id.clone()
+    }
     
     fn is_region_claimed(&self, start: usize, end: usize) -> bool {
         self.claimed_regions.iter().any(|region| {
@@ -608,10 +2294,10 @@ impl Engine {
     
     fn apply_redaction_mode(&self, original: &str, canonical_id: &str, config: &serde_json::Value) -> String {
         // If static replacement is provided in config (e.g. for Simple Replace), use it
-        if let Some(replacement) = config.get("replacement").and_then(|v| v.as_str()) {
-            if !replacement.is_empty() {
-                return replacement.to_string();
-            }
+        if let Some(replacement) = config.get("replacement").and_then(|v| v.as_str())
+            && !replacement.is_empty()
+        {
+            return replacement.to_string();
         }
 
         let mode = config.get("mode")
@@ -663,3 +2349,850 @@ impl Engine {
         }
     }
 }
+
+/// Shannon entropy in bits per character.
+fn shannon_entropy(value: &str) -> f64 {
+    let mut counts: HashMap<char, usize> = HashMap::new();
+    for c in value.chars() {
+        *counts.entry(c).or_insert(0) += 1;
+    }
+
+    let len = value.chars().count() as f64;
+    counts.values()
+        .map(|&n| {
+            let p = n as f64 / len;
+            -p * p.log2()
+        })
+        .sum()
+}
+
+/// True when a hex value is a git SHA or content digest, judged by the text just before it.
+fn is_known_hash_context(text: &str, start: usize, end: usize) -> bool {
+    let value = &text[start..end];
+    if !value.chars().all(|c| c.is_ascii_hexdigit()) {
+        return false;
+    }
+
+    let window_start = text.floor_char_boundary(start.saturating_sub(24));
+    let before = text[window_start..start].to_ascii_lowercase();
+
+    // Docker and OCI digests: image@sha256:<hex>, "digest": "sha256:<hex>"
+    if before.ends_with("sha256:") || before.ends_with("sha512:") {
+        return true;
+    }
+
+    // Git SHAs: commit <sha>, HEAD is now at <sha>, rev=<sha>, ... The keyword must be the whole word
+    // right before the value, so "shared_secret=" or "X-Auth-Header-Token:" don't count
+    if (7..=40).contains(&value.len()) {
+        let lead = before.trim_end_matches(|c: char| !c.is_ascii_alphanumeric() && c != '_');
+        return ["commit", "sha", "sha1", "rev", "revision", "head", "merge", "parent", "tree", "is now at"]
+            .iter()
+            .any(|keyword| {
+                lead.strip_suffix(keyword)
+                    .is_some_and(|rest| !rest.ends_with(|c: char| c.is_ascii_alphanumeric() || c == '_'))
+            });
+    }
+
+    false
+}
+
+/// Canonical form of a detected value for fingerprinting, per detector type.
+/// Returns `None` when the raw text is already canonical.
+fn normalize_value(type_lower: &str, value: &str) -> Option<String> {
+    match type_lower {
+        "email" | "hostname" | "uuid" => Some(value.trim_end_matches('.').to_lowercase()),
+        "mac" => Some(value.chars().filter(|c| c.is_ascii_hexdigit()).collect::<String>().to_lowercase()),
+        "ipv4" => value.parse::<std::net::Ipv4Addr>().ok()
+            .or_else(|| {
+                // Zero-padded octets (010.000.001.002) don't parse as an address directly
+                let octets: Vec<u8> = value.split('.').filter_map(|o| o.parse().ok()).collect();
+                <[u8; 4]>::try_from(octets).ok().map(std::net::Ipv4Addr::from)
+            })
+            .map(|ip| ip.to_string()),
+        "ipv6" => value.parse::<std::net::Ipv6Addr>().ok().map(|ip| ip.to_string()),
+        "credit_card" | "ssn" | "bank_account" => Some(value.chars().filter(|c| c.is_ascii_digit()).collect()),
+        "national_id" => Some(value.chars().filter(|c| c.is_ascii_alphanumeric()).collect::<String>().to_uppercase()),
+        "iban" => Some(value.chars().filter(|c| !c.is_whitespace()).collect::<String>().to_uppercase()),
+        "device" => Some(value.chars().filter(|c| c.is_ascii_alphanumeric()).collect::<String>().to_lowercase()),
+        "phone" => normalize_phone(value, "US"),
+        _ => None,
+    }
+}
+
+/// MAC addresses in various formats: AA:BB:CC:DD:EE:FF, AA-BB-CC-DD-EE-FF, AABBCCDDEEFF
+const MAC_PATTERN: &str = r"(?i)\b(?:[0-9a-f]{2}[:-]){5}[0-9a-f]{2}\b|\b[0-9a-f]{12}\b";
+
+/// Indentation of a YAML line, counting a list item's "- " as part of its key's indent.
+fn yaml_indent(line: &str) -> usize {
+    let spaces = line.len() - line.trim_start().len();
+    let rest = &line[spaces..];
+    match rest.strip_prefix('-') {
+        Some(after) if after.starts_with(' ') => spaces + 1 + yaml_indent(after),
+        _ => spaces,
+    }
+}
+
+/// The `kind:` of the YAML mapping containing the key on line `index` at `indent`, searching the
+/// sibling keys above and below it within the same mapping.
+fn yaml_object_kind(lines: &[(usize, &str)], index: usize, indent: usize) -> Option<String> {
+    let kind_regex = Regex::new(r#"^\s*(?:-\s+)*kind:\s*["']?([A-Za-z]+)["']?\s*$"#).unwrap();
+    let is_item_start = |line: &str| line.trim_start().starts_with("- ") && yaml_indent(line) == indent;
+    let check = |line: &str| (yaml_indent(line) == indent).then(|| kind_regex.captures(line).map(|c| c[1].to_string())).flatten();
+
+    // The key's own line may open the list item, in which case nothing above belongs to it
+    if !is_item_start(lines[index].1) {
+        for &(_, line) in lines[..index].iter().rev() {
+            if line.trim().is_empty() || line.trim_start().starts_with('#') || yaml_indent(line) > indent {
+                continue;
+            }
+            if line.starts_with("---") || yaml_indent(line) < indent {
+                break;
+            }
+            if let Some(kind) = check(line) {
+                return Some(kind);
+            }
+            if is_item_start(line) {
+                break;
+            }
+        }
+    }
+    for &(_, line) in &lines[index + 1..] {
+        if line.trim().is_empty() || line.trim_start().starts_with('#') || yaml_indent(line) > indent {
+            continue;
+        }
+        if line.starts_with("---") || yaml_indent(line) < indent || is_item_start(line) {
+            break;
+        }
+        if let Some(kind) = check(line) {
+            return Some(kind);
+        }
+    }
+    None
+}
+
+/// Byte offsets of matching `{` and `}` pairs, skipping braces inside JSON strings.
+fn json_brace_pairs(text: &str) -> Vec<(usize, usize)> {
+    let mut pairs = Vec::new();
+    let mut stack = Vec::new();
+    let mut in_string = false;
+    let mut escaped = false;
+    for (i, b) in text.bytes().enumerate() {
+        if in_string {
+            match b {
+                _ if escaped => escaped = false,
+                b'\\' => escaped = true,
+                b'"' | b'\n' => in_string = false,
+                _ => {}
+            }
+            continue;
+        }
+        match b {
+            b'"' => in_string = true,
+            b'{' => stack.push(i),
+            b'}' => {
+                if let Some(open) = stack.pop() {
+                    pairs.push((open, i));
+                }
+            }
+            _ => {}
+        }
+    }
+    pairs
+}
+
+/// Byte offset of the `]` closing the JSON array opened at `open` (or the end of the text),
+/// skipping brackets inside JSON strings.
+fn json_array_end(text: &str, open: usize) -> usize {
+    let mut depth = 0;
+    let mut in_string = false;
+    let mut escaped = false;
+    for (i, b) in text.bytes().enumerate().skip(open) {
+        if in_string {
+            match b {
+                _ if escaped => escaped = false,
+                b'\\' => escaped = true,
+                b'"' | b'\n' => in_string = false,
+                _ => {}
+            }
+            continue;
+        }
+        match b {
+            b'"' => in_string = true,
+            b'[' => depth += 1,
+            b']' => {
+                depth -= 1;
+                if depth == 0 {
+                    return i;
+                }
+            }
+            _ => {}
+        }
+    }
+    text.len()
+}
+
+/// Component name for a cloud resource type: `role` becomes ROLE, `virtualMachines` VIRTUALMACHINES.
+fn cloud_resource_kind(resource_type: &str) -> String {
+    let kind: String = resource_type.chars()
+        .map(|c| if c.is_ascii_alphanumeric() { c.to_ascii_uppercase() } else { '_' })
+        .collect();
+    if kind.is_empty() { "RESOURCE".to_string() } else { kind }
+}
+
+/// Built-in authorities whose accounts (NT AUTHORITY\\SYSTEM, BUILTIN\\Administrators) identify no one.
+/// Matched against the last word of the authority name.
+const WELL_KNOWN_WINDOWS_DOMAINS: &[&str] = &["BUILTIN", "AUTHORITY", "SERVICE", "APPPOOL", "WORKGROUP"];
+
+/// Registry hive roots, which start key paths (HKLM\SOFTWARE\Microsoft) rather than accounts.
+const REGISTRY_HIVES: &[&str] = &["HKLM", "HKCU", "HKCR", "HKU", "HKCC", "HKPD"];
+
+const WELL_KNOWN_WINDOWS_ACCOUNTS: &[&str] = &[
+    "SYSTEM", "LOCAL", "NETWORK", "ADMINISTRATOR", "ADMINISTRATORS", "GUEST", "KRBTGT", "USERS", "EVERYONE", "ANONYMOUS",
+];
+
+/// Default Active Directory containers that appear as CN= in DNs without naming an object.
+const WELL_KNOWN_AD_CONTAINERS: &[&str] = &[
+    "Users", "Computers", "Builtin", "System", "Configuration", "Schema", "Program Data", "ForeignSecurityPrincipals",
+    "Managed Service Accounts", "Domain Admins", "Domain Users", "Domain Computers", "Enterprise Admins", "Administrators",
+];
+
+/// ISO 3166-1 alpha-2 country codes.
+const COUNTRY_CODES: &str = "AD AE AF AG AI AL AM AO AQ AR AS AT AU AW AX AZ BA BB BD BE BF BG BH BI BJ BL BM BN BO BQ BR BS BT BV BW BY BZ \
+    CA CC CD CF CG CH CI CK CL CM CN CO CR CU CV CW CX CY CZ DE DJ DK DM DO DZ EC EE EG EH ER ES ET FI FJ FK FM FO FR \
+    GA GB GD GE GF GG GH GI GL GM GN GP GQ GR GS GT GU GW GY HK HM HN HR HT HU ID IE IL IM IN IO IQ IR IS IT JE JM JO JP \
+    KE KG KH KI KM KN KP KR KW KY KZ LA LB LC LI LK LR LS LT LU LV LY MA MC MD ME MF MG MH MK ML MM MN MO MP MQ MR MS MT \
+    MU MV MW MX MY MZ NA NC NE NF NG NI NL NO NP NR NU NZ OM PA PE PF PG PH PK PL PM PN PR PS PT PW PY QA RE RO RS RU RW \
+    SA SB SC SD SE SG SH SI SJ SK SL SM SN SO SR SS ST SV SX SY SZ TC TD TF TG TH TJ TK TL TM TN TO TR TT TV TW TZ UA UG \
+    UM US UY UZ VA VC VE VG VI VN VU WF WS XK YE YT ZA ZM ZW";
+
+fn is_country_code(code: &str) -> bool {
+    code.len() == 2 && COUNTRY_CODES.split_whitespace().any(|c| c == code)
+}
+
+/// True when `position` falls inside a double-quoted string on its line, counting unescaped quotes before it.
+fn in_json_string(text: &str, position: usize) -> bool {
+    let line_start = text[..position].rfind('\n').map_or(0, |i| i + 1);
+    let mut escaped = false;
+    let mut inside = false;
+    for c in text[line_start..position].chars() {
+        match c {
+            '\\' if !escaped => escaped = true,
+            '"' if !escaped => inside = !inside,
+            _ => escaped = false,
+        }
+    }
+    inside
+}
+
+/// True when one of `keywords` appears (case-insensitively) within `window` bytes before `start`.
+fn preceded_by_keyword(text: &str, start: usize, keywords: &[&str], window: usize) -> bool {
+    let window_start = text.floor_char_boundary(start.saturating_sub(window));
+    let before = text[window_start..start].to_lowercase();
+    keywords.iter().any(|keyword| before.contains(keyword))
+}
+
+/// Like `preceded_by_keyword`, but the keyword has to stand as a whole word ("sin" but not "using").
+fn preceded_by_word(text: &str, start: usize, keywords: &[&str], window: usize) -> bool {
+    let window_start = text.floor_char_boundary(start.saturating_sub(window));
+    let before = text[window_start..start].to_lowercase();
+    keywords.iter().any(|keyword| {
+        before.match_indices(keyword).any(|(i, _)| {
+            !before[..i].ends_with(|c: char| c.is_alphanumeric())
+                && !before[i + keyword.len()..].starts_with(|c: char| c.is_alphanumeric())
+        })
+    })
+}
+
+/// IBAN lengths by country, per the SWIFT IBAN registry.
+const IBAN_LENGTHS: &[(&str, usize)] = &[
+    ("AD", 24), ("AE", 23), ("AL", 28), ("AT", 20), ("AZ", 28), ("BA", 20), ("BE", 16), ("BG", 22), ("BH", 22),
+    ("BR", 29), ("BY", 28), ("CH", 21), ("CR", 22), ("CY", 28), ("CZ", 24), ("DE", 22), ("DK", 18), ("DO", 28),
+    ("EE", 20), ("EG", 29), ("ES", 24), ("FI", 18), ("FO", 18), ("FR", 27), ("GB", 22), ("GE", 22), ("GI", 23),
+    ("GL", 18), ("GR", 27), ("GT", 28), ("HR", 21), ("HU", 28), ("IE", 22), ("IL", 23), ("IQ", 23), ("IS", 26),
+    ("IT", 27), ("JO", 30), ("KW", 30), ("KZ", 20), ("LB", 28), ("LC", 32), ("LI", 21), ("LT", 20), ("LU", 20),
+    ("LV", 21), ("MC", 27), ("MD", 24), ("ME", 22), ("MK", 19), ("MR", 27), ("MT", 31), ("MU", 30), ("NL", 18),
+    ("NO", 15), ("PK", 24), ("PL", 28), ("PS", 29), ("PT", 25), ("QA", 29), ("RO", 24), ("RS", 22), ("SA", 24),
+    ("SC", 31), ("SE", 24), ("SI", 19), ("SK", 24), ("SM", 27), ("ST", 25), ("SV", 28), ("TL", 23), ("TN", 24),
+    ("TR", 26), ("UA", 29), ("VA", 22), ("VG", 24), ("XK", 20),
+];
+
+/// Checks an IBAN (with or without group spaces) against its country length and the ISO 7064 mod-97 checksum.
+fn is_valid_iban(candidate: &str) -> bool {
+    let iban: String = candidate.chars().filter(|c| !c.is_whitespace()).collect();
+    let Some(&(_, length)) = IBAN_LENGTHS.iter().find(|(country, _)| iban.starts_with(country)) else {
+        return false;
+    };
+    if iban.len() != length {
+        return false;
+    }
+
+    // Move the country code and check digits to the end, then read letters as 10..=35
+    let rearranged = iban[4..].chars().chain(iban[..4].chars());
+    let mut remainder = 0u32;
+    for c in rearranged {
+        let Some(value) = c.to_digit(36) else {
+            return false;
+        };
+        remainder = if value < 10 { (remainder * 10 + value) % 97 } else { (remainder * 100 + value) % 97 };
+    }
+    remainder == 1
+}
+
+/// Checks the ISO 3779 check digit in position nine of a VIN.
+fn is_valid_vin(vin: &str) -> bool {
+    const WEIGHTS: [u32; 17] = [8, 7, 6, 5, 4, 3, 2, 10, 0, 9, 8, 7, 6, 5, 4, 3, 2];
+    let value = |c: char| match c {
+        '0'..='9' => c.to_digit(10),
+        'A' | 'J' => Some(1),
+        'B' | 'K' | 'S' => Some(2),
+        'C' | 'L' | 'T' => Some(3),
+        'D' | 'M' | 'U' => Some(4),
+        'E' | 'N' | 'V' => Some(5),
+        'F' | 'W' => Some(6),
+        'G' | 'P' | 'X' => Some(7),
+        'H' | 'Y' => Some(8),
+        'R' | 'Z' => Some(9),
+        _ => None,
+    };
+
+    let chars: Vec<char> = vin.chars().collect();
+    if chars.len() != 17 || !chars.iter().any(|c| c.is_ascii_alphabetic()) {
+        return false;
+    }
+    let Some(sum) = chars.iter().zip(WEIGHTS).map(|(&c, w)| value(c).map(|v| v * w)).sum::<Option<u32>>() else {
+        return false;
+    };
+    let expected = match sum % 11 {
+        10 => 'X',
+        r => char::from_digit(r, 10).unwrap(),
+    };
+    chars[8] == expected
+}
+
+/// Decodes a 25-byte Base58Check address and returns its version byte if the checksum holds.
+fn base58check_version(address: &str) -> Option<u8> {
+    let bytes = bs58::decode(address).into_vec().ok()?;
+    if bytes.len() != 25 {
+        return None;
+    }
+    let (payload, checksum) = bytes.split_at(21);
+    let hash = Sha256::digest(Sha256::digest(payload));
+    (hash[..4] == *checksum).then_some(payload[0])
+}
+
+/// EIP-55: a letter is uppercase exactly when the matching nibble of keccak256(lowercase address) is 8 or more.
+fn is_valid_eip55(hex_part: &str) -> bool {
+    let lower = hex_part.to_lowercase();
+    let hash = Keccak256::digest(lower.as_bytes());
+    hex_part.chars().enumerate().all(|(i, c)| {
+        let nibble = if i % 2 == 0 { hash[i / 2] >> 4 } else { hash[i / 2] & 0x0f };
+        !c.is_ascii_alphabetic() || c.is_ascii_uppercase() == (nibble >= 8)
+    })
+}
+
+/// Checks a nine-digit ABA routing transit number: Federal Reserve prefix and the 3-7-1 checksum.
+fn is_valid_aba_routing(number: &str) -> bool {
+    let digits: Vec<u32> = number.chars().filter_map(|c| c.to_digit(10)).collect();
+    if digits.len() != 9 {
+        return false;
+    }
+
+    let prefix = digits[0] * 10 + digits[1];
+    if !matches!(prefix, 0..=12 | 21..=32 | 61..=72 | 80) {
+        return false;
+    }
+
+    let checksum: u32 = digits.chunks(3).map(|d| 3 * d[0] + 7 * d[1] + d[2]).sum();
+    checksum.is_multiple_of(10)
+}
+
+/// A national identifier format: the issuing country (the per-country toggle), the id kind used
+/// in the type prefix, a shape pattern, a check-digit validator and the keywords one of which
+/// must come shortly before it under `requireContext` (none when the shape alone is distinctive).
+struct NationalIdFormat {
+    country: &'static str,
+    kind: &'static str,
+    pattern: &'static str,
+    is_valid: fn(&str) -> bool,
+    keywords: &'static [&'static str],
+}
+
+const NATIONAL_ID_FORMATS: &[NationalIdFormat] = &[
+    NationalIdFormat { country: "GB", kind: "NINO", pattern: r"\b[A-CEGHJ-PR-TW-Z][A-CEGHJ-NPR-TW-Z] ?\d{2} ?\d{2} ?\d{2} ?[A-D]\b", is_valid: is_valid_nino, keywords: &[] },
+    NationalIdFormat { country: "CA", kind: "SIN", pattern: r"\b\d{3}[ -]?\d{3}[ -]?\d{3}\b", is_valid: is_valid_sin, keywords: &["sin", "social insurance"] },
+    NationalIdFormat { country: "IN", kind: "AADHAAR", pattern: r"\b[2-9]\d{3}[ -]?\d{4}[ -]?\d{4}\b", is_valid: |v| verhoeff_valid(&digits_of(v)), keywords: &["aadhaar", "aadhar", "uidai"] },
+    NationalIdFormat { country: "IN", kind: "PAN", pattern: r"\b[A-Z]{3}[PCHFATBLJG][A-Z]\d{4}[A-Z]\b", is_valid: |_| true, keywords: &[] },
+    NationalIdFormat { country: "AU", kind: "TFN", pattern: r"\b\d{3}[ -]?\d{3}[ -]?\d{2,3}\b", is_valid: is_valid_tfn, keywords: &["tfn", "tax file"] },
+    NationalIdFormat { country: "ES", kind: "DNI", pattern: r"\b\d{8}-?[A-Z]\b", is_valid: is_valid_dni, keywords: &[] },
+    NationalIdFormat { country: "ES", kind: "NIE", pattern: r"\b[XYZ]-?\d{7}-?[A-Z]\b", is_valid: is_valid_dni, keywords: &[] },
+    NationalIdFormat { country: "IT", kind: "CF", pattern: r"\b[A-Z]{6}\d{2}[A-EHLMPR-T]\d{2}[A-Z]\d{3}[A-Z]\b", is_valid: is_valid_codice_fiscale, keywords: &[] },
+    NationalIdFormat { country: "FR", kind: "NIR", pattern: r"\b[12] ?\d{2} ?\d{2} ?(?:\d{2}|2[AB]) ?\d{3} ?\d{3} ?\d{2}\b", is_valid: is_valid_nir, keywords: &[] },
+    NationalIdFormat { country: "BR", kind: "CPF", pattern: r"\b\d{3}\.?\d{3}\.?\d{3}-?\d{2}\b", is_valid: is_valid_cpf, keywords: &[] },
+    NationalIdFormat { country: "BR", kind: "CNPJ", pattern: r"\b\d{2}\.?\d{3}\.?\d{3}/?\d{4}-?\d{2}\b", is_valid: is_valid_cnpj, keywords: &[] },
+];
+
+fn digits_of(value: &str) -> Vec<u32> {
+    value.chars().filter_map(|c| c.to_digit(10)).collect()
+}
+
+/// Luhn (mod 10) check over the digits, the last being the check digit.
+fn luhn_valid(digits: &[u32]) -> bool {
+    let sum: u32 = digits.iter()
+        .rev()
+        .enumerate()
+        .map(|(i, &d)| if i % 2 == 1 { if d * 2 > 9 { d * 2 - 9 } else { d * 2 } } else { d })
+        .sum();
+    !digits.is_empty() && sum.is_multiple_of(10)
+}
+
+/// Verhoeff check over the digits, the last being the check digit.
+fn verhoeff_valid(digits: &[u32]) -> bool {
+    const D: [[usize; 10]; 10] = [
+        [0, 1, 2, 3, 4, 5, 6, 7, 8, 9], [1, 2, 3, 4, 0, 6, 7, 8, 9, 5], [2, 3, 4, 0, 1, 7, 8, 9, 5, 6],
+        [3, 4, 0, 1, 2, 8, 9, 5, 6, 7], [4, 0, 1, 2, 3, 9, 5, 6, 7, 8], [5, 9, 8, 7, 6, 0, 4, 3, 2, 1],
+        [6, 5, 9, 8, 7, 1, 0, 4, 3, 2], [7, 6, 5, 9, 8, 2, 1, 0, 4, 3], [8, 7, 6, 5, 9, 3, 2, 1, 0, 4],
+        [9, 8, 7, 6, 5, 4, 3, 2, 1, 0],
+    ];
+    const P: [[usize; 10]; 8] = [
+        [0, 1, 2, 3, 4, 5, 6, 7, 8, 9], [1, 5, 7, 6, 2, 8, 3, 0, 9, 4], [5, 8, 0, 3, 7, 9, 6, 1, 4, 2],
+        [8, 9, 1, 6, 0, 4, 3, 5, 2, 7], [9, 4, 5, 3, 1, 2, 6, 8, 7, 0], [4, 2, 8, 6, 5, 7, 3, 9, 0, 1],
+        [2, 7, 9, 3, 8, 0, 6, 4, 1, 5], [7, 0, 4, 6, 9, 1, 3, 2, 5, 8],
+    ];
+    let check = digits.iter().rev().enumerate().fold(0, |c, (i, &d)| D[c][P[i % 8][d as usize]]);
+    !digits.is_empty() && check == 0
+}
+
+/// UK National Insurance number: the prefix pairs below are never issued.
+fn is_valid_nino(value: &str) -> bool {
+    let prefix: String = value.chars().take(2).collect();
+    !["BG", "GB", "NK", "KN", "TN", "NT", "ZZ"].contains(&prefix.as_str())
+}
+
+/// Canadian Social Insurance Number: Luhn, and 0 and 8 are never issued as the first digit.
+fn is_valid_sin(value: &str) -> bool {
+    let digits = digits_of(value);
+    !matches!(digits.first(), Some(0) | Some(8)) && luhn_valid(&digits)
+}
+
+/// Australian Tax File Number: weighted sum divisible by 11 (eight or nine digits).
+fn is_valid_tfn(value: &str) -> bool {
+    let digits = digits_of(value);
+    let weights: &[u32] = match digits.len() {
+        9 => &[1, 4, 3, 7, 5, 8, 6, 9, 10],
+        8 => &[10, 7, 8, 4, 6, 3, 5, 1],
+        _ => return false,
+    };
+    digits.iter().zip(weights).map(|(d, w)| d * w).sum::<u32>().is_multiple_of(11)
+}
+
+/// Spanish DNI and NIE: the control letter is the number mod 23 (NIE X/Y/Z read as 0/1/2).
+fn is_valid_dni(value: &str) -> bool {
+    let compact: String = value.chars().filter(|c| *c != '-').collect();
+    let (number, letter) = compact.split_at(compact.len() - 1);
+    let number = number.replacen('X', "0", 1).replacen('Y', "1", 1).replacen('Z', "2", 1);
+    let Ok(n) = number.parse::<usize>() else {
+        return false;
+    };
+    "TRWAGMYFPDXBNJZSQVHLCKE".chars().nth(n % 23).map(String::from).as_deref() == Some(letter)
+}
+
+/// Italian codice fiscale: the final letter checks the first fifteen characters.
+fn is_valid_codice_fiscale(value: &str) -> bool {
+    const ODD: [u32; 26] = [1, 0, 5, 7, 9, 13, 15, 17, 19, 21, 2, 4, 18, 20, 11, 3, 6, 8, 12, 14, 16, 10, 22, 25, 24, 23];
+    let chars: Vec<char> = value.chars().collect();
+    let sum: u32 = chars[..15].iter().enumerate().map(|(i, c)| {
+        // Digits weigh the same as the letter at their index (0 = A)
+        let index = c.to_digit(10).unwrap_or_else(|| *c as u32 - 'A' as u32);
+        if i % 2 == 0 { ODD[index as usize] } else { index }
+    }).sum();
+    char::from_u32('A' as u32 + sum % 26) == Some(chars[15])
+}
+
+/// French NIR (social security number): key is 97 minus the first thirteen digits mod 97.
+/// Corsican departments 2A and 2B are read as 19 and 18.
+fn is_valid_nir(value: &str) -> bool {
+    let compact: String = value.chars().filter(|c| !c.is_whitespace()).collect();
+    let (body, key) = compact.split_at(13);
+    let body = body.replace("2A", "19").replace("2B", "18");
+    match (body.parse::<u64>(), key.parse::<u64>()) {
+        (Ok(body), Ok(key)) => 97 - body % 97 == key,
+        _ => false,
+    }
+}
+
+/// Brazilian CPF: two mod-11 check digits; repeated-digit numbers are invalid placeholders.
+fn is_valid_cpf(value: &str) -> bool {
+    let digits = digits_of(value);
+    if digits.len() != 11 || digits.iter().all(|&d| d == digits[0]) {
+        return false;
+    }
+    let check = |len: usize| {
+        let sum: u32 = digits[..len].iter().enumerate().map(|(i, d)| d * (len as u32 + 1 - i as u32)).sum();
+        let r = sum * 10 % 11;
+        if r == 10 { 0 } else { r }
+    };
+    check(9) == digits[9] && check(10) == digits[10]
+}
+
+/// Brazilian CNPJ: two weighted mod-11 check digits.
+fn is_valid_cnpj(value: &str) -> bool {
+    let digits = digits_of(value);
+    if digits.len() != 14 || digits.iter().all(|&d| d == digits[0]) {
+        return false;
+    }
+    let check = |len: usize| {
+        let weights = [6, 5, 4, 3, 2, 9, 8, 7, 6, 5, 4, 3, 2];
+        let sum: u32 = digits[..len].iter().zip(&weights[13 - len..]).map(|(d, w)| d * w).sum();
+        let r = sum % 11;
+        if r < 2 { 0 } else { 11 - r }
+    };
+    check(12) == digits[12] && check(13) == digits[13]
+}
+
+const FIRST_NAMES: &str = include_str!("data/first_names.txt");
+const SURNAMES: &str = include_str!("data/surnames.txt");
+
+/// Phrases that typically precede a person's name in logs and correspondence.
+const NAME_CUES: &[&str] = &[
+    "dear", "hi", "hello", "thanks", "regards", "user", "username", "customer", "employee", "contact",
+    "contacted", "assigned to", "assignee", "owner", "reporter", "approved by", "signed", "cc", "name",
+    "by", "from", "with", "called", "emailed", "met",
+];
+
+const PUBLIC_SUFFIX_LIST: &str = include_str!("data/public_suffix_list.txt");
+
+/// Suffixes used for private networks that the public list leaves out.
+const PRIVATE_SUFFIXES: &[&str] = &["internal", "local", "localdomain", "lan", "corp", "intranet", "home"];
+
+/// File extensions that are also top-level domains; two-label matches ending in these are file names.
+const FILE_EXTENSION_TLDS: &[&str] = &[
+    "rs", "py", "sh", "pl", "pm", "md", "so", "ps", "cc", "tf", "mo", "mk", "zip", "mov", "rb", "kt",
+];
+
+/// Reverse-DNS package roots: java.util.List, com.google.common, org.apache.commons.io.
+const PACKAGE_ROOTS: &[&str] = &["java", "javax", "jdk", "sun", "com", "org", "net", "io", "android", "androidx", "kotlin", "scala", "edu", "gov"];
+
+/// Public suffix rules, split into exact, wildcard (`*.ck`) and exception (`!www.ck`) sets.
+struct PublicSuffixList {
+    rules: std::collections::HashSet<&'static str>,
+    wildcards: std::collections::HashSet<&'static str>,
+    exceptions: std::collections::HashSet<&'static str>,
+}
+
+impl PublicSuffixList {
+    fn new() -> Self {
+        let mut list = PublicSuffixList {
+            rules: PRIVATE_SUFFIXES.iter().copied().collect(),
+            wildcards: Default::default(),
+            exceptions: Default::default(),
+        };
+        for rule in PUBLIC_SUFFIX_LIST.lines().map(str::trim).filter(|l| !l.is_empty() && !l.starts_with('#')) {
+            if let Some(parent) = rule.strip_prefix("*.") {
+                list.wildcards.insert(parent);
+            } else if let Some(exception) = rule.strip_prefix('!') {
+                list.exceptions.insert(exception);
+            } else {
+                list.rules.insert(rule);
+            }
+        }
+        list
+    }
+
+    /// Number of trailing labels forming the public suffix of a lowercase name, or None when the
+    /// top-level domain is unknown (so the name is not a hostname).
+    fn suffix_len(&self, labels: &[&str]) -> Option<usize> {
+        for i in 0..labels.len() {
+            let candidate = labels[i..].join(".");
+            if self.exceptions.contains(candidate.as_str()) {
+                return Some(labels.len() - i - 1);
+            }
+            let wildcard = i + 1 < labels.len() && self.wildcards.contains(labels[i + 1..].join(".").as_str());
+            if self.rules.contains(candidate.as_str()) || wildcard {
+                return Some(labels.len() - i);
+            }
+        }
+        None
+    }
+}
+
+/// Whether a hostname-shaped match is really code: a file name, a dotted package or module path,
+/// an import, or a method call.
+fn is_code_reference(text: &str, start: usize, end: usize, labels: &[&str]) -> bool {
+    let after = &text[end..];
+    // docs.rs/serde or crates.sh:443 is still a host
+    let file_name = labels.len() == 2 && FILE_EXTENSION_TLDS.contains(&labels[1]) && !after.starts_with(['/', ':']);
+    let package = labels.len() >= 3 && PACKAGE_ROOTS.contains(&labels[0]);
+    let member_access = after.starts_with('(') || (after.starts_with('.') && after[1..].starts_with(|c: char| c.is_ascii_uppercase() || c == '*'));
+    // Only the module token straight after the keyword: import a.b.c, from a.b import x, package a.b;
+    let line_start = text[..start].rfind('\n').map_or(0, |i| i + 1);
+    let keyword = text[line_start..start].trim();
+    let line_rest = after.split('\n').next().unwrap_or("").trim_end();
+    let statement_end = line_rest.is_empty() || line_rest.starts_with([';', ',']);
+    let import = match keyword {
+        "import" | "package" => statement_end || line_rest.trim_start().starts_with("as "),
+        "from" => line_rest.trim_start().starts_with("import "),
+        "using" | "require" => statement_end,
+        _ => false,
+    };
+    file_name || package || member_access || import
+}
+
+/// Maps each name in a frequency-ordered list to a weight in 0..=1, highest for the most common.
+fn name_ranks(list: &str) -> HashMap<&str, f64> {
+    let names: Vec<&str> = list.lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty() && !line.starts_with('#'))
+        .collect();
+    let total = names.len() as f64;
+    names.iter().enumerate().map(|(rank, name)| (*name, 1.0 - rank as f64 / total)).collect()
+}
+
+/// Capitalized words that are never part of a name: cues, sentence starters, log levels and calendar words.
+fn is_name_stopword(word: &str) -> bool {
+    const STOPWORDS: &[&str] = &[
+        "The", "This", "That", "These", "Those", "A", "An", "In", "On", "At", "For", "With", "To", "From", "And", "Or",
+        "But", "If", "When", "Then", "I", "We", "You", "He", "She", "They", "It", "My", "Our", "Your", "Please",
+        "Thanks", "Hello", "Hi", "Dear", "User", "Customer", "Employee", "Error", "Warning", "Info", "Debug", "Trace",
+        "Fatal", "Mr", "Mrs", "Ms", "Miss", "Dr", "Prof", "Monday", "Tuesday", "Wednesday", "Thursday", "Friday",
+        "Saturday", "Sunday", "January", "February", "March", "April", "May", "June", "July", "August", "September",
+        "October", "November", "December",
+    ];
+    STOPWORDS.contains(&word)
+}
+
+/// Keeps the coarse area part of a postcode and masks the rest: the UK outward code, the Canadian
+/// forward sortation area, otherwise the first `keep_digits` characters.
+fn generalize_postcode(country: &str, postcode: &str, keep_digits: usize) -> String {
+    let keep = match country {
+        // The inward code / local delivery unit is always the last three characters
+        "GB" | "CA" => postcode.chars().filter(|c| !c.is_whitespace()).count() - 3,
+        _ => keep_digits,
+    };
+
+    let mut kept = 0;
+    postcode.chars()
+        .map(|c| {
+            if !c.is_ascii_alphanumeric() {
+                c
+            } else if kept < keep {
+                kept += 1;
+                c
+            } else {
+                'X'
+            }
+        })
+        .collect()
+}
+
+const MONTH_NAMES: [&str; 12] = [
+    "January", "February", "March", "April", "May", "June",
+    "July", "August", "September", "October", "November", "December",
+];
+
+fn month_from_name(name: &str) -> Option<u32> {
+    let prefix = name.get(..3)?.to_lowercase();
+    MONTH_NAMES.iter().position(|m| m[..3].to_lowercase() == prefix).map(|i| i as u32 + 1)
+}
+
+fn days_in_month(year: i64, month: u32) -> u32 {
+    match month {
+        2 if (year % 4 == 0 && year % 100 != 0) || year % 400 == 0 => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    }
+}
+
+/// Days since 1970-01-01 for a proleptic Gregorian date (Howard Hinnant's algorithm).
+fn days_from_civil(year: i64, month: u32, day: u32) -> i64 {
+    let y = if month <= 2 { year - 1 } else { year };
+    let era = y.div_euclid(400);
+    let yoe = y - era * 400;
+    let mp = (month as i64 + 9) % 12;
+    let doy = (153 * mp + 2) / 5 + day as i64 - 1;
+    let doe = yoe * 365 + yoe / 4 - yoe / 100 + doy;
+    era * 146097 + doe - 719468
+}
+
+/// Inverse of `days_from_civil`.
+fn civil_from_days(days: i64) -> (i64, u32, u32) {
+    let z = days + 719468;
+    let era = z.div_euclid(146097);
+    let doe = z - era * 146097;
+    let yoe = (doe - doe / 1460 + doe / 36524 - doe / 146096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = (doy - (153 * mp + 2) / 5 + 1) as u32;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 } as u32;
+    (yoe + era * 400 + i64::from(month <= 2), month, day)
+}
+
+/// Rewrites the date parts of a matched date in place, keeping its format, padding, month-name
+/// style and any time of day. The weekday is recomputed for the new date.
+fn shift_date(cap: &regex::Captures, year: i64, month: u32, day: u32, shift_days: i64) -> String {
+    let days = days_from_civil(year, month, day) + shift_days;
+    let (new_year, new_month, new_day) = civil_from_days(days);
+    let weekday = ["Thu", "Fri", "Sat", "Sun", "Mon", "Tue", "Wed"][days.rem_euclid(7) as usize];
+
+    let whole = cap.get(0).unwrap();
+    // Two-digit fields stay two digits wide (01/15 -> 05/03), one-digit fields stay unpadded
+    let pad = |value: u32, original: &str| {
+        if original.len() == 2 { format!("{:02}", value) } else { value.to_string() }
+    };
+    let mut parts: Vec<(usize, usize, String)> = Vec::new();
+    if let Some(y) = cap.name("y") {
+        parts.push((y.start(), y.end(), format!("{:04}", new_year)));
+    }
+    if let Some(m) = cap.name("m") {
+        parts.push((m.start(), m.end(), pad(new_month, m.as_str())));
+    }
+    if let Some(mon) = cap.name("mon") {
+        let name = MONTH_NAMES[new_month as usize - 1];
+        let full = MONTH_NAMES.iter().any(|m| m.eq_ignore_ascii_case(mon.as_str()));
+        let name = if full { name.to_string() } else { name[..3].to_string() };
+        let upper = mon.as_str().chars().all(|c| c.is_ascii_uppercase());
+        parts.push((mon.start(), mon.end(), if upper { name.to_uppercase() } else { name }));
+    }
+    if let Some(d) = cap.name("d") {
+        parts.push((d.start(), d.end(), pad(new_day, d.as_str())));
+    }
+    if let Some(wd) = cap.name("wd") {
+        parts.push((wd.start(), wd.end(), weekday.to_string()));
+    }
+
+    // Splice from the right so earlier offsets stay valid
+    parts.sort_by_key(|p| std::cmp::Reverse(p.0));
+    let mut shifted = whole.as_str().to_string();
+    for (start, end, value) in parts {
+        shifted.replace_range(start - whole.start()..end - whole.start(), &value);
+    }
+    shifted
+}
+
+/// Numbering plan summary for a country: calling code, national number lengths and whether a
+/// leading `0` trunk prefix is dialled domestically.
+struct PhoneRegion {
+    region: &'static str,
+    code: &'static str,
+    min_len: usize,
+    max_len: usize,
+    trunk: bool,
+}
+
+const PHONE_REGIONS: &[PhoneRegion] = &[
+    PhoneRegion { region: "US", code: "1", min_len: 10, max_len: 10, trunk: false },
+    PhoneRegion { region: "CA", code: "1", min_len: 10, max_len: 10, trunk: false },
+    PhoneRegion { region: "GB", code: "44", min_len: 9, max_len: 10, trunk: true },
+    PhoneRegion { region: "IE", code: "353", min_len: 7, max_len: 9, trunk: true },
+    PhoneRegion { region: "DE", code: "49", min_len: 6, max_len: 13, trunk: true },
+    PhoneRegion { region: "FR", code: "33", min_len: 9, max_len: 9, trunk: true },
+    PhoneRegion { region: "ES", code: "34", min_len: 9, max_len: 9, trunk: false },
+    PhoneRegion { region: "IT", code: "39", min_len: 6, max_len: 11, trunk: false },
+    PhoneRegion { region: "PT", code: "351", min_len: 9, max_len: 9, trunk: false },
+    PhoneRegion { region: "NL", code: "31", min_len: 9, max_len: 9, trunk: true },
+    PhoneRegion { region: "BE", code: "32", min_len: 8, max_len: 9, trunk: true },
+    PhoneRegion { region: "CH", code: "41", min_len: 9, max_len: 9, trunk: true },
+    PhoneRegion { region: "AT", code: "43", min_len: 4, max_len: 13, trunk: true },
+    PhoneRegion { region: "SE", code: "46", min_len: 7, max_len: 9, trunk: true },
+    PhoneRegion { region: "NO", code: "47", min_len: 8, max_len: 8, trunk: false },
+    PhoneRegion { region: "DK", code: "45", min_len: 8, max_len: 8, trunk: false },
+    PhoneRegion { region: "PL", code: "48", min_len: 9, max_len: 9, trunk: false },
+    PhoneRegion { region: "IN", code: "91", min_len: 10, max_len: 10, trunk: true },
+    PhoneRegion { region: "AU", code: "61", min_len: 9, max_len: 9, trunk: true },
+    PhoneRegion { region: "NZ", code: "64", min_len: 8, max_len: 10, trunk: true },
+    PhoneRegion { region: "JP", code: "81", min_len: 9, max_len: 10, trunk: true },
+    PhoneRegion { region: "CN", code: "86", min_len: 10, max_len: 11, trunk: true },
+    PhoneRegion { region: "SG", code: "65", min_len: 8, max_len: 8, trunk: false },
+    PhoneRegion { region: "AE", code: "971", min_len: 8, max_len: 9, trunk: true },
+    PhoneRegion { region: "ZA", code: "27", min_len: 9, max_len: 9, trunk: true },
+    PhoneRegion { region: "BR", code: "55", min_len: 10, max_len: 11, trunk: false },
+    PhoneRegion { region: "MX", code: "52", min_len: 10, max_len: 10, trunk: false },
+];
+
+/// Validates a phone number candidate and returns its E.164 form (`+15550100199`).
+/// Numbers without an international prefix are read in `default_region`'s national format.
+fn normalize_phone(candidate: &str, default_region: &str) -> Option<String> {
+    let digits: String = candidate.chars().filter(|c| c.is_ascii_digit()).collect();
+    let has_separator = candidate.contains([' ', '-', '.', '(']);
+
+    let international = if candidate.starts_with('+') {
+        Some(digits.as_str())
+    } else if candidate.starts_with("00") {
+        digits.strip_prefix("00")
+    } else {
+        None
+    };
+
+    let (region, national) = if let Some(rest) = international {
+        let region = PHONE_REGIONS.iter().find(|r| rest.starts_with(r.code))?;
+        // "+44 (0)20 ..." style numbers include the trunk prefix in parentheses
+        let national = &rest[region.code.len()..];
+        let national = if region.trunk { national.strip_prefix('0').unwrap_or(national) } else { national };
+        (region, national)
+    } else {
+        // Bare digit runs are too often ids or counters; national numbers need some formatting
+        if !has_separator {
+            return None;
+        }
+        let region_code = if default_region == "UK" { "GB" } else { default_region };
+        let region = PHONE_REGIONS.iter().find(|r| r.region == region_code)?;
+        let national = if region.trunk {
+            digits.strip_prefix('0')?
+        } else if region.code == "1" && digits.len() == 11 {
+            digits.strip_prefix('1')?
+        } else {
+            digits.as_str()
+        };
+        (region, national)
+    };
+
+    if national.len() < region.min_len || national.len() > region.max_len {
+        return None;
+    }
+    // North American area codes never start with 0 or 1
+    if region.code == "1" && national.starts_with(['0', '1']) {
+        return None;
+    }
+
+    Some(format!("+{}{}", region.code, national))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Runs a single step of `step_type` with `config` over `input`.
+    fn redact(step_type: &str, config: serde_json::Value, input: &str) -> String {
+        let pipeline = serde_json::json!({
+            "version": 1,
+            "steps": [{ "id": "1", "type": step_type, "enabled": true, "config": config }],
+        });
+        Engine::new().run_pipeline(input, &pipeline.to_string()).unwrap()
+    }
+
+    #[test]
+    fn shifted_date_keeps_two_digit_fields() {
+        // Jan 15 + 109 days is May 3, whose month and day both drop to one digit
+        let us = Regex::new(r"(?P<m>\d{1,2})/(?P<d>\d{1,2})/(?P<y>\d{4})").unwrap();
+        let dotted = Regex::new(r"(?P<d>\d{1,2})\.(?P<m>\d{1,2})\.(?P<y>\d{4})").unwrap();
+        assert_eq!(shift_date(&us.captures("01/15/2024").unwrap(), 2024, 1, 15, 109), "05/03/2024");
+        assert_eq!(shift_date(&dotted.captures("15.01.2024").unwrap(), 2024, 1, 15, 109), "03.05.2024");
+        assert_eq!(shift_date(&us.captures("1/5/2024").unwrap(), 2024, 1, 5, 109), "4/23/2024");
+    }
+
+    #[test]
+    fn windows_account_skips_registry_paths() {
+        let config = serde_json::json!({});
+        for key in [r"HKLM\SOFTWARE\Microsoft\Windows", r"HKCU\Software\Classes", r"HKEY_LOCAL_MACHINE\SYSTEM", r"HKCU\Environment"] {
+            assert_eq!(redact("windows_identity", config.clone(), key), key);
+        }
+        assert_eq!(redact("windows_identity", config, r"CORP\jsmith logged on"), r"CORP\<WIN_USER_1> logged on");
+    }
+
+    #[test]
+    fn windows_account_needs_two_character_user() {
+        let config = serde_json::json!({});
+        assert_eq!(redact("windows_identity", config.clone(), r"match A\d+ here"), r"match A\d+ here");
+        assert_eq!(redact("windows_identity", config, r"line ABC\n next"), r"line ABC\n next");
+    }
+}
@@ -37,23 +37,45 @@ const AVAILABLE_OPERATIONS = [
   { type: 'email', name: 'Email Address', category: 'Identity', icon: '📧' },
   { type: 'phone', name: 'Phone Number', category: 'Identity', icon: '📱' },
   { type: 'username', name: 'Username', category: 'Identity', icon: '👤' },
+  { type: 'path', name: 'File Paths & Home Dirs', category: 'Identity', icon: '📂' },
+  { type: 'windows_identity', name: 'Windows & Active Directory', category: 'Identity', icon: '🪟' },
+  { type: 'person_name', name: 'Person Name', category: 'Identity', icon: '🧑' },
+  { type: 'address', name: 'Postal Address', category: 'PII', icon: '🏠' },
+  { type: 'date', name: 'Dates & Timestamps', category: 'PII', icon: '📅' },
+  { type: 'geo', name: 'Geolocation', category: 'PII', icon: '📍' },
   { type: 'ipv4', name: 'IPv4 Address', category: 'Infrastructure', icon: '🌐' },
   { type: 'ipv6', name: 'IPv6 Address', category: 'Infrastructure', icon: '🌍' },
   { type: 'mac', name: 'MAC Address', category: 'Infrastructure', icon: '🔌' },
+  { type: 'device', name: 'Device Identifiers', category: 'Infrastructure', icon: '📱' },
+  { type: 'cloud_resource', name: 'Cloud Resource IDs', category: 'Infrastructure', icon: '☁️' },
+  { type: 'kubernetes', name: 'Kubernetes Secrets', category: 'Secrets', icon: '☸️' },
   { type: 'hostname', name: 'Hostname/FQDN', category: 'Infrastructure', icon: '🖥️' },
   { type: 'url', name: 'URL', category: 'Infrastructure', icon: '🔗' },
+  { type: 'connection_string', name: 'Connection String', category: 'Secrets', icon: '🔌' },
   { type: 'jwt', name: 'JWT Token', category: 'Secrets', icon: '🔑' },
   { type: 'apikey', name: 'API Key', category: 'Secrets', icon: '🗝️' },
   { type: 'oauth', name: 'OAuth Token', category: 'Secrets', icon: '🛡️' },
   { type: 'base64', name: 'Base64 Blob', category: 'Secrets', icon: '🔐' },
+  { type: 'entropy', name: 'High-Entropy Secret', category: 'Secrets', icon: '🎲' },
+  { type: 'password', name: 'Password Assignment', category: 'Secrets', icon: '🔏' },
+  { type: 'authorization', name: 'Authorization Header', category: 'Secrets', icon: '🎫' },
   { type: 'uuid', name: 'UUID', category: 'Identifiers', icon: '🆔' },
   { type: 'ssn', name: 'SSN', category: 'PII', icon: '🔒' },
+  { type: 'national_id', name: 'National ID Pack', category: 'PII', icon: '🪪' },
   { type: 'credit_card', name: 'Credit Card', category: 'Financial', icon: '💳' },
+  { type: 'iban', name: 'IBAN', category: 'Financial', icon: '🏦' },
+  { type: 'bic', name: 'SWIFT/BIC Code', category: 'Financial', icon: '🌐' },
+  { type: 'routing_number', name: 'ABA Routing Number', category: 'Financial', icon: '🧾' },
+  { type: 'bank_account', name: 'Bank Account Number', category: 'Financial', icon: '💰' },
+  { type: 'crypto_wallet', name: 'Crypto Wallet Address', category: 'Financial', icon: '🪙' },
+  { type: 'vin', name: 'Vehicle Identification Number', category: 'Identifiers', icon: '🚗' },
   { type: 'regex', name: 'Custom Regex', category: 'Advanced', icon: '⚡' },
   { type: 'jsonKey', name: 'JSON Key', category: 'Structure', icon: '{}' },
   { type: 'queryParam', name: 'URL Parameter', category: 'Structure', icon: '?' },
   { type: 'header', name: 'HTTP Header', category: 'Structure', icon: '↕️' },
+  { type: 'cookie', name: 'Cookies', category: 'Structure', icon: '🍪' },
   { type: 'replace', name: 'Find & Replace', category: 'Advanced', icon: '🔍' },
+  { type: 'dictionary', name: 'Dictionary', category: 'Advanced', icon: '📖' },
   { type: 'partialMask', name: 'Partial Mask', category: 'Advanced', icon: '🌑' },
 ];
 
@@ -103,6 +125,11 @@ function App() {
           ? newConfig.allowedDomains.split(',').map((d: string) => d.trim()).filter(Boolean)
           : (newConfig.allowedDomains || []);
       }
+      if (step.type === 'hostname') {
+        newConfig.allowedDomains = typeof newConfig.allowedDomains === 'string'
+          ? newConfig.allowedDomains.split(',').map((d: string) => d.trim()).filter(Boolean)
+          : (newConfig.allowedDomains || []);
+      }
       if (step.type === 'ipv4') {
         newConfig.excludeSubnets = typeof newConfig.excludeSubnets === 'string'
           ? newConfig.excludeSubnets.split(',').map((s: string) => s.trim()).filter(Boolean)
@@ -113,6 +140,27 @@ function App() {
           ? newConfig.keys.split(',').map((k: string) => k.trim()).filter(Boolean)
           : (newConfig.keys || []);
       }
+      if (step.type === 'dictionary' && typeof newConfig.terms === 'string') {
+        newConfig.terms = newConfig.terms.split(/\r?\n/).map((t: string) => t.trim()).filter(Boolean);
+      }
+      if (step.type === 'jwt' && typeof newConfig.claims === 'string') {
+        newConfig.claims = newConfig.claims.split(',').map((c: string) => c.trim()).filter(Boolean);
+      }
+      if (step.type === 'password' && typeof newConfig.keys === 'string') {
+        newConfig.keys = newConfig.keys.split(',').map((k: string) => k.trim()).filter(Boolean);
+      }
+      if (step.type === 'connection_string' && typeof newConfig.params === 'string') {
+        newConfig.params = newConfig.params.split(',').map((p: string) => p.trim()).filter(Boolean);
+      }
+      if (step.type === 'username' && typeof newConfig.triggers === 'string') {
+        newConfig.triggers = newConfig.triggers.split(',').map((t: string) => t.trim()).filter(Boolean);
+      }
+      if (step.type === 'path' && typeof newConfig.segments === 'string') {
+        newConfig.segments = newConfig.segments.split(',').map((s: string) => s.trim()).filter(Boolean);
+      }
+      if (step.type === 'cookie' && typeof newConfig.names === 'string') {
+        newConfig.names = newConfig.names.split(',').map((n: string) => n.trim()).filter(Boolean);
+      }
       if (step.type === 'queryParam' || step.type === 'header') {
         newConfig.names = typeof newConfig.names === 'string'
           ? newConfig.names.split(',').map((n: string) => n.trim()).filter(Boolean)
@@ -627,6 +675,8 @@ function App() {
                               contextBefore={entry?.context_before}
                               contextAfter={entry?.context_after}
                               method={entry?.method}
+                              entropy={entry?.entropy}
+                              confidence={entry?.confidence}
                               isHighlighted={highlightedToken === part}
                               onHighlight={setHighlightedToken}
                             />
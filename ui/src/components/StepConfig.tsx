@@ -77,6 +77,36 @@ export const StepConfig: React.FC<StepConfigProps> = ({ type, config, label, onL
         return null;
     };
 
+    const renderIpModeSelector = () => (
+        <div>
+            <label className="block text-xs font-semibold text-[#9ca3af] mb-1">
+                Address Handling
+                <HelpIcon text="Prefix-preserving anonymization maps each address to a realistic-looking one keyed by this session, so hosts in the same subnet stay in the same subnet." />
+            </label>
+            <select
+                value={config.ipMode || 'redact'}
+                onChange={(e) => handleChange('ipMode', e.target.value)}
+                className="w-full px-3 py-1.5 bg-[#0f172a] border border-[#1f2937] rounded text-sm text-[#e5e7eb] focus:outline-none focus:border-[#38bdf8]"
+            >
+                <option value="redact">Redact</option>
+                <option value="prefix_preserving">Prefix-Preserving Pseudonym</option>
+            </select>
+        </div>
+    );
+
+    const renderToggle = (key: string, text: string, help: string, defaultValue = false) => (
+        <label className="flex items-center gap-2 text-xs font-semibold text-[#9ca3af] cursor-pointer">
+            <input
+                type="checkbox"
+                checked={config[key] ?? defaultValue}
+                onChange={(e) => handleChange(key, e.target.checked)}
+                className="accent-[#38bdf8]"
+            />
+            {text}
+            <HelpIcon text={help} />
+        </label>
+    );
+
     const renderConfig = () => {
         switch (type) {
             case 'email':
@@ -100,6 +130,42 @@ export const StepConfig: React.FC<StepConfigProps> = ({ type, config, label, onL
                     </div>
                 );
 
+            case 'hostname':
+                return (
+                    <div className="space-y-3">
+                        <div>
+                            <label className="block text-xs font-semibold text-[#9ca3af] mb-1">
+                                Hostname Handling
+                                <HelpIcon text="Only names ending in a known public suffix (or a private one like .internal) are treated as hostnames, so file names and package paths are left alone. Per-label pseudonyms keep the topology visible: db1 and db2 under the same prod.eu share their parent ids." />
+                            </label>
+                            <select
+                                value={config.hostnameMode || 'full'}
+                                onChange={(e) => handleChange('hostnameMode', e.target.value)}
+                                className="w-full px-3 py-1.5 bg-[#0f172a] border border-[#1f2937] rounded text-sm text-[#e5e7eb] focus:outline-none focus:border-[#38bdf8]"
+                            >
+                                <option value="full">Redact Full Hostname</option>
+                                <option value="registrable">Keep Registrable Domain</option>
+                                <option value="labels">Pseudonymize Each Label</option>
+                            </select>
+                        </div>
+                        <div>
+                            <label className="block text-xs font-semibold text-[#9ca3af] mb-1">
+                                Allowed Domains (CSV)
+                                <HelpIcon text="Hostnames equal to or under these domains will NOT be redacted, e.g. vendor or CDN domains." />
+                            </label>
+                            <input
+                                type="text"
+                                value={config.allowedDomains || ''}
+                                onChange={(e) => handleChange('allowedDomains', e.target.value)}
+                                placeholder="e.g. amazonaws.com, cloudflare.com"
+                                className="w-full px-3 py-1.5 bg-[#0f172a] border border-[#1f2937] rounded text-sm text-[#e5e7eb] focus:outline-none focus:border-[#38bdf8]"
+                            />
+                        </div>
+                        {renderModeSelector()}
+                        {renderMaskOptions()}
+                    </div>
+                );
+
             case 'ipv4':
                 return (
                     <div className="space-y-3">
@@ -116,8 +182,18 @@ export const StepConfig: React.FC<StepConfigProps> = ({ type, config, label, onL
                                 className="w-full px-3 py-1.5 bg-[#0f172a] border border-[#1f2937] rounded text-sm text-[#e5e7eb] focus:outline-none focus:border-[#38bdf8]"
                             />
                         </div>
-                        {renderModeSelector()}
-                        {renderMaskOptions()}
+                        {renderIpModeSelector()}
+                        {config.ipMode !== 'prefix_preserving' && renderModeSelector()}
+                        {config.ipMode !== 'prefix_preserving' && renderMaskOptions()}
+                    </div>
+                );
+
+            case 'ipv6':
+                return (
+                    <div className="space-y-3">
+                        {renderIpModeSelector()}
+                        {config.ipMode !== 'prefix_preserving' && renderModeSelector()}
+                        {config.ipMode !== 'prefix_preserving' && renderMaskOptions()}
                     </div>
                 );
 
@@ -142,6 +218,591 @@ export const StepConfig: React.FC<StepConfigProps> = ({ type, config, label, onL
                     </div>
                 );
 
+            case 'entropy':
+                return (
+                    <div className="space-y-3">
+                        <div>
+                            <label className="block text-xs font-semibold text-[#9ca3af] mb-1">
+                                Minimum Length
+                                <HelpIcon text="Candidate strings shorter than this are never flagged, however random they look." />
+                            </label>
+                            <input
+                                type="number"
+                                min="8"
+                                value={config.minLength || 20}
+                                onChange={(e) => handleChange('minLength', parseInt(e.target.value))}
+                                className="w-full px-3 py-1.5 bg-[#0f172a] border border-[#1f2937] rounded text-sm text-[#e5e7eb] focus:outline-none focus:border-[#38bdf8]"
+                            />
+                        </div>
+                        <div className="grid grid-cols-3 gap-3">
+                            <div>
+                                <label className="block text-xs font-semibold text-[#9ca3af] mb-1">Hex</label>
+                                <input
+                                    type="number"
+                                    step="0.1"
+                                    value={config.hexThreshold ?? 3.0}
+                                    onChange={(e) => handleChange('hexThreshold', parseFloat(e.target.value))}
+                                    className="w-full px-3 py-1.5 bg-[#0f172a] border border-[#1f2937] rounded text-sm text-[#e5e7eb] focus:outline-none focus:border-[#38bdf8]"
+                                />
+                            </div>
+                            <div>
+                                <label className="block text-xs font-semibold text-[#9ca3af] mb-1">Base64</label>
+                                <input
+                                    type="number"
+                                    step="0.1"
+                                    value={config.base64Threshold ?? 4.0}
+                                    onChange={(e) => handleChange('base64Threshold', parseFloat(e.target.value))}
+                                    className="w-full px-3 py-1.5 bg-[#0f172a] border border-[#1f2937] rounded text-sm text-[#e5e7eb] focus:outline-none focus:border-[#38bdf8]"
+                                />
+                            </div>
+                            <div>
+                                <label className="block text-xs font-semibold text-[#9ca3af] mb-1">
+                                    Other
+                                    <HelpIcon text="Entropy thresholds in bits per character for each charset. UUIDs, git SHAs and docker digests are always skipped." />
+                                </label>
+                                <input
+                                    type="number"
+                                    step="0.1"
+                                    value={config.asciiThreshold ?? 4.0}
+                                    onChange={(e) => handleChange('asciiThreshold', parseFloat(e.target.value))}
+                                    className="w-full px-3 py-1.5 bg-[#0f172a] border border-[#1f2937] rounded text-sm text-[#e5e7eb] focus:outline-none focus:border-[#38bdf8]"
+                                />
+                            </div>
+                        </div>
+                        {renderModeSelector()}
+                        {renderMaskOptions()}
+                    </div>
+                );
+
+            case 'connection_string':
+                return (
+                    <div className="space-y-3">
+                        <div className="space-y-1.5">
+                            {renderToggle('redactUser', 'Redact Username', 'Also redact the user part of user:password@host and JDBC user= properties. Passwords are always redacted.')}
+                            {renderToggle('redactHost', 'Redact Hosts', 'Redact each host in the authority while keeping ports, paths and the database name.', true)}
+                        </div>
+                        <div>
+                            <label className="block text-xs font-semibold text-[#9ca3af] mb-1">
+                                Sensitive Parameters (CSV)
+                                <HelpIcon text="Query parameters and JDBC properties whose values are redacted. Leave empty to use the built-in list (password, token, key, sig, ...)." />
+                            </label>
+                            <input
+                                type="text"
+                                value={config.params || ''}
+                                onChange={(e) => handleChange('params', e.target.value)}
+                                placeholder="e.g. password, token, sig"
+                                className="w-full px-3 py-1.5 bg-[#0f172a] border border-[#1f2937] rounded text-sm text-[#e5e7eb] focus:outline-none focus:border-[#38bdf8]"
+                            />
+                        </div>
+                        {renderModeSelector()}
+                        {renderMaskOptions()}
+                    </div>
+                );
+
+            case 'password':
+                return (
+                    <div className="space-y-3">
+                        <div>
+                            <label className="block text-xs font-semibold text-[#9ca3af] mb-1">
+                                Key Vocabulary (CSV)
+                                <HelpIcon text="Key names containing any of these words have their value redacted in key=value, key: value, --flag value and JSON syntaxes. Leave empty for the built-in list." />
+                            </label>
+                            <input
+                                type="text"
+                                value={config.keys || ''}
+                                onChange={(e) => handleChange('keys', e.target.value)}
+                                placeholder="e.g. password, secret, token"
+                                className="w-full px-3 py-1.5 bg-[#0f172a] border border-[#1f2937] rounded text-sm text-[#e5e7eb] focus:outline-none focus:border-[#38bdf8]"
+                            />
+                        </div>
+                        {renderModeSelector()}
+                        {renderMaskOptions()}
+                    </div>
+                );
+
+            case 'username':
+                return (
+                    <div className="space-y-3">
+                        <div>
+                            <label className="block text-xs font-semibold text-[#9ca3af] mb-1">
+                                Trigger Keywords (CSV)
+                                <HelpIcon text="The word following any of these is treated as a username. Leave empty for the built-in list: @, user=, username=, login=, uid=, owner:, /home/, /users/." />
+                            </label>
+                            <input
+                                type="text"
+                                value={config.triggers || ''}
+                                onChange={(e) => handleChange('triggers', e.target.value)}
+                                placeholder="e.g. login=, uid=, owner:"
+                                className="w-full px-3 py-1.5 bg-[#0f172a] border border-[#1f2937] rounded text-sm text-[#e5e7eb] focus:outline-none focus:border-[#38bdf8]"
+                            />
+                        </div>
+                        {renderModeSelector()}
+                        {renderMaskOptions()}
+                    </div>
+                );
+
+            case 'windows_identity': {
+                const identifiers: Record<string, string> = {
+                    sid: 'Domain SIDs',
+                    account: 'DOMAIN\\user',
+                    upn: 'UPNs',
+                    dn: 'Distinguished Names',
+                    machine: 'Machine Names',
+                };
+                const enabled: string[] = config.identifiers || Object.keys(identifiers);
+                const toggleIdentifier = (id: string) => handleChange(
+                    'identifiers',
+                    enabled.includes(id) ? enabled.filter(i => i !== id) : [...enabled, id]
+                );
+                return (
+                    <div className="space-y-3">
+                        <div>
+                            <label className="block text-xs font-semibold text-[#9ca3af] mb-1">
+                                Identifiers
+                                <HelpIcon text="Only user-specific parts are redacted: the domain part of S-1-5-21 SIDs, user names, CN values and computer names. Well-known SIDs (S-1-5-18), built-in accounts and default AD containers stay visible." />
+                            </label>
+                            <div className="grid grid-cols-2 gap-1.5">
+                                {Object.entries(identifiers).map(([id, name]) => (
+                                    <label key={id} className="flex items-center gap-2 text-xs text-[#9ca3af] cursor-pointer">
+                                        <input
+                                            type="checkbox"
+                                            checked={enabled.includes(id)}
+                                            onChange={() => toggleIdentifier(id)}
+                                            className="accent-[#38bdf8]"
+                                        />
+                                        {name}
+                                    </label>
+                                ))}
+                            </div>
+                        </div>
+                        {renderToggle('redactDomain', 'Redact Domains', 'Also redact NetBIOS domains, UPN suffixes and DC= components.')}
+                        {renderModeSelector()}
+                        {renderMaskOptions()}
+                    </div>
+                );
+            }
+
+            case 'cloud_resource':
+                return (
+                    <div className="space-y-3">
+                        {renderToggle('redactResourceNames', 'Redact Resource Names', 'Redact role, function, bucket, resource group and other resource names. Service and resource types always stay visible.', true)}
+                        {renderToggle('redactRegion', 'Redact Regions', 'Also redact the region in ARNs. AWS account, Azure subscription and tenant, and GCP project ids are always redacted.')}
+                        {renderModeSelector()}
+                        {renderMaskOptions()}
+                    </div>
+                );
+
+            case 'kubernetes':
+                return (
+                    <div className="space-y-3">
+                        {renderToggle('configMaps', 'Include ConfigMaps', 'Redact ConfigMap data as well as Secret data and stringData. Base64 values are decoded so the same secret gets the same id wherever it appears.', true)}
+                        {renderToggle('redactNodes', 'Redact Node Names', 'Redact nodeName, kubernetes.io/hostname labels and host IPs.')}
+                        {renderToggle('redactNamespaces', 'Redact Namespaces', 'Redact namespace fields and --namespace flags. Built-in namespaces such as kube-system are kept.')}
+                        {renderToggle('redactPodIps', 'Redact Pod IPs', 'Redact podIP and podIPs entries.')}
+                        {renderModeSelector()}
+                        {renderMaskOptions()}
+                    </div>
+                );
+
+            case 'path':
+                return (
+                    <div className="space-y-3">
+                        {renderToggle('redactUser', 'Redact Home Directory Users', 'Redact the user in C:\\Users\\x, /home/x, /Users/x and ~x. Shared profiles such as Public are kept.', true)}
+                        <div>
+                            <label className="block text-xs font-semibold text-[#9ca3af] mb-1">
+                                Sensitive Segments (CSV)
+                                <HelpIcon text="Directory or file names to redact anywhere in a path, e.g. project or customer folders. Use * as a wildcard. The full original path is kept in the sidecar." />
+                            </label>
+                            <input
+                                type="text"
+                                value={config.segments || ''}
+                                onChange={(e) => handleChange('segments', e.target.value)}
+                                placeholder="e.g. acme-*, project-falcon"
+                                className="w-full px-3 py-1.5 bg-[#0f172a] border border-[#1f2937] rounded text-sm text-[#e5e7eb] focus:outline-none focus:border-[#38bdf8]"
+                            />
+                        </div>
+                        {renderModeSelector()}
+                        {renderMaskOptions()}
+                    </div>
+                );
+
+            case 'authorization':
+                return (
+                    <div className="space-y-3">
+                        {renderToggle('redactUser', 'Redact Usernames', 'Redact the username decoded from Basic credentials and the Digest username parameter. The scheme and passwords are handled regardless.', true)}
+                        {renderModeSelector()}
+                        {renderMaskOptions()}
+                    </div>
+                );
+
+            case 'cookie':
+                return (
+                    <div className="space-y-3">
+                        <div>
+                            <label className="block text-xs font-semibold text-[#9ca3af] mb-1">
+                                Cookie Names (CSV)
+                                <HelpIcon text="Only cookies matching these names are redacted; use * as a wildcard. Leave empty to redact every cookie value. Set-Cookie attributes like Path, Expires and HttpOnly are always kept." />
+                            </label>
+                            <input
+                                type="text"
+                                value={config.names || ''}
+                                onChange={(e) => handleChange('names', e.target.value)}
+                                placeholder="e.g. session*, JSESSIONID, *_token"
+                                className="w-full px-3 py-1.5 bg-[#0f172a] border border-[#1f2937] rounded text-sm text-[#e5e7eb] focus:outline-none focus:border-[#38bdf8]"
+                            />
+                        </div>
+                        {renderModeSelector()}
+                        {renderMaskOptions()}
+                    </div>
+                );
+
+            case 'jwt':
+                return (
+                    <div className="space-y-3">
+                        {renderToggle('partial', 'Keep Header & Claims', 'Decode the token and redact only the signature and the listed claims, so alg, iss, exp and iat stay readable.')}
+                        {config.partial && (
+                            <>
+                                <div>
+                                    <label className="block text-xs font-semibold text-[#9ca3af] mb-1">
+                                        Redacted Claims (CSV)
+                                        <HelpIcon text="Payload claims whose values are redacted. All other claims are kept as-is. Defaults to sub, email, name." />
+                                    </label>
+                                    <input
+                                        type="text"
+                                        value={config.claims || ''}
+                                        onChange={(e) => handleChange('claims', e.target.value)}
+                                        placeholder="e.g. sub, email, name"
+                                        className="w-full px-3 py-1.5 bg-[#0f172a] border border-[#1f2937] rounded text-sm text-[#e5e7eb] focus:outline-none focus:border-[#38bdf8]"
+                                    />
+                                </div>
+                                <div>
+                                    <label className="block text-xs font-semibold text-[#9ca3af] mb-1">
+                                        Output
+                                        <HelpIcon text="Re-encode a token whose signature is replaced by the canonical id (it will never verify), or emit the decoded header and payload as JSON." />
+                                    </label>
+                                    <select
+                                        value={config.output || 'token'}
+                                        onChange={(e) => handleChange('output', e.target.value)}
+                                        className="w-full px-3 py-1.5 bg-[#0f172a] border border-[#1f2937] rounded text-sm text-[#e5e7eb] focus:outline-none focus:border-[#38bdf8]"
+                                    >
+                                        <option value="token">Re-encoded Token</option>
+                                        <option value="summary">Decoded JSON Summary</option>
+                                    </select>
+                                </div>
+                            </>
+                        )}
+                        {renderModeSelector()}
+                        {renderMaskOptions()}
+                    </div>
+                );
+
+            case 'phone':
+                return (
+                    <div className="space-y-3">
+                        <div>
+                            <label className="block text-xs font-semibold text-[#9ca3af] mb-1">
+                                Default Region
+                                <HelpIcon text="Numbers without a +country prefix are read in this region's national format. All formats of the same number share one token (E.164 normalization)." />
+                            </label>
+                            <select
+                                value={config.defaultRegion || 'US'}
+                                onChange={(e) => handleChange('defaultRegion', e.target.value)}
+                                className="w-full px-3 py-1.5 bg-[#0f172a] border border-[#1f2937] rounded text-sm text-[#e5e7eb] focus:outline-none focus:border-[#38bdf8]"
+                            >
+                                {['US', 'CA', 'GB', 'IE', 'DE', 'FR', 'ES', 'IT', 'PT', 'NL', 'BE', 'CH', 'AT', 'SE', 'NO', 'DK', 'PL', 'IN', 'AU', 'NZ', 'JP', 'CN', 'SG', 'AE', 'ZA', 'BR', 'MX'].map(region => (
+                                    <option key={region} value={region}>{region}</option>
+                                ))}
+                            </select>
+                        </div>
+                        {renderModeSelector()}
+                        {renderMaskOptions()}
+                    </div>
+                );
+
+            case 'bic':
+            case 'routing_number':
+                return (
+                    <div className="space-y-3">
+                        {renderToggle('requireContext', 'Require Keyword', `Only redact ${type === 'bic' ? 'codes' : 'numbers'} shortly after a keyword like ${type === 'bic' ? 'SWIFT, BIC or bank' : 'routing, ABA or transit'}. Without it, ${type === 'bic' ? 'ordinary upper-case words' : 'any checksum-valid nine-digit number'} may be redacted.`, true)}
+                        {renderModeSelector()}
+                        {renderMaskOptions()}
+                    </div>
+                );
+
+            case 'national_id': {
+                const countries: Record<string, string> = {
+                    GB: 'UK National Insurance',
+                    CA: 'Canada SIN',
+                    IN: 'India Aadhaar & PAN',
+                    AU: 'Australia TFN',
+                    ES: 'Spain DNI/NIE',
+                    IT: 'Italy Codice Fiscale',
+                    FR: 'France NIR',
+                    BR: 'Brazil CPF/CNPJ',
+                };
+                const enabled: string[] = config.countries || Object.keys(countries);
+                const toggleCountry = (code: string) => handleChange(
+                    'countries',
+                    enabled.includes(code) ? enabled.filter(c => c !== code) : [...enabled, code]
+                );
+                return (
+                    <div className="space-y-3">
+                        <div>
+                            <label className="block text-xs font-semibold text-[#9ca3af] mb-1">
+                                Countries
+                                <HelpIcon text="Identifiers are only redacted when their check digits validate, which keeps random numbers from matching." />
+                            </label>
+                            <div className="grid grid-cols-2 gap-1.5">
+                                {Object.entries(countries).map(([code, name]) => (
+                                    <label key={code} className="flex items-center gap-2 text-xs text-[#9ca3af] cursor-pointer">
+                                        <input
+                                            type="checkbox"
+                                            checked={enabled.includes(code)}
+                                            onChange={() => toggleCountry(code)}
+                                            className="accent-[#38bdf8]"
+                                        />
+                                        {name}
+                                    </label>
+                                ))}
+                            </div>
+                        </div>
+                        {renderToggle('requireContext', 'Require Keyword', 'Only redact Canadian SINs, Australian TFNs and Aadhaar numbers shortly after a keyword like SIN, TFN or Aadhaar. Their checksums pass about one random number in ten, so without it order and transaction numbers may be redacted.', true)}
+                        {renderModeSelector()}
+                        {renderMaskOptions()}
+                    </div>
+                );
+            }
+
+            case 'device': {
+                const identifiers: Record<string, string> = {
+                    imei: 'IMEI',
+                    imsi: 'IMSI',
+                    iccid: 'SIM ICCID',
+                    advertising_id: 'Advertising IDs',
+                    serial: 'Serial Numbers',
+                    mac: 'MAC Addresses',
+                };
+                const enabled: string[] = config.identifiers || Object.keys(identifiers);
+                const toggleIdentifier = (id: string) => handleChange(
+                    'identifiers',
+                    enabled.includes(id) ? enabled.filter(i => i !== id) : [...enabled, id]
+                );
+                return (
+                    <div className="space-y-3">
+                        <div>
+                            <label className="block text-xs font-semibold text-[#9ca3af] mb-1">
+                                Identifiers
+                                <HelpIcon text="IMEI and ICCID must pass their Luhn check. IMSIs, advertising IDs and serial numbers are only redacted next to a keyword such as imsi=, IDFA or Serial:." />
+                            </label>
+                            <div className="grid grid-cols-2 gap-1.5">
+                                {Object.entries(identifiers).map(([id, name]) => (
+                                    <label key={id} className="flex items-center gap-2 text-xs text-[#9ca3af] cursor-pointer">
+                                        <input
+                                            type="checkbox"
+                                            checked={enabled.includes(id)}
+                                            onChange={() => toggleIdentifier(id)}
+                                            className="accent-[#38bdf8]"
+                                        />
+                                        {name}
+                                    </label>
+                                ))}
+                            </div>
+                        </div>
+                        {renderModeSelector()}
+                        {renderMaskOptions()}
+                    </div>
+                );
+            }
+
+            case 'crypto_wallet': {
+                const currencies: Record<string, string> = {
+                    BTC: 'Bitcoin',
+                    ETH: 'Ethereum',
+                    LTC: 'Litecoin',
+                    DOGE: 'Dogecoin',
+                    TRX: 'Tron',
+                };
+                const enabled: string[] = config.currencies || Object.keys(currencies);
+                const toggleCurrency = (code: string) => handleChange(
+                    'currencies',
+                    enabled.includes(code) ? enabled.filter(c => c !== code) : [...enabled, code]
+                );
+                return (
+                    <div className="space-y-3">
+                        <div>
+                            <label className="block text-xs font-semibold text-[#9ca3af] mb-1">
+                                Currencies
+                                <HelpIcon text="Addresses are only redacted when their checksum validates: Base58Check and bech32 for Bitcoin-family coins, EIP-55 for mixed-case Ethereum addresses." />
+                            </label>
+                            <div className="grid grid-cols-2 gap-1.5">
+                                {Object.entries(currencies).map(([code, name]) => (
+                                    <label key={code} className="flex items-center gap-2 text-xs text-[#9ca3af] cursor-pointer">
+                                        <input
+                                            type="checkbox"
+                                            checked={enabled.includes(code)}
+                                            onChange={() => toggleCurrency(code)}
+                                            className="accent-[#38bdf8]"
+                                        />
+                                        {name}
+                                    </label>
+                                ))}
+                            </div>
+                        </div>
+                        {renderModeSelector()}
+                        {renderMaskOptions()}
+                    </div>
+                );
+            }
+
+            case 'vin':
+                return (
+                    <div className="space-y-3">
+                        {renderToggle('requireCheckDigit', 'Require Check Digit', 'North American VINs carry a check digit in position 9. Turn off to also redact European VINs that appear after a VIN or chassis keyword.', true)}
+                        {renderModeSelector()}
+                        {renderMaskOptions()}
+                    </div>
+                );
+
+            case 'person_name':
+                return (
+                    <div className="space-y-3">
+                        <div>
+                            <label className="block text-xs font-semibold text-[#9ca3af] mb-1">
+                                Minimum Confidence: {Math.round((config.minConfidence ?? 0.6) * 100)}%
+                                <HelpIcon text="Names are scored from bundled first-name and surname lists, capitalization and cues like Mr., Dear or assigned to. Lower values catch more names but also more ordinary words." />
+                            </label>
+                            <input
+                                type="range"
+                                min="0.3"
+                                max="1"
+                                step="0.05"
+                                value={config.minConfidence ?? 0.6}
+                                onChange={(e) => handleChange('minConfidence', parseFloat(e.target.value))}
+                                className="w-full accent-[#38bdf8]"
+                            />
+                        </div>
+                        {renderModeSelector()}
+                        {renderMaskOptions()}
+                    </div>
+                );
+
+            case 'address':
+                return (
+                    <div className="space-y-3">
+                        <div>
+                            <label className="block text-xs font-semibold text-[#9ca3af] mb-1">
+                                Postcode Handling
+                                <HelpIcon text="Street addresses are always redacted. Postcodes (US ZIP, UK, Canada, NL, AU) can instead be generalized to their leading area or to the country." />
+                            </label>
+                            <select
+                                value={config.generalize || 'none'}
+                                onChange={(e) => handleChange('generalize', e.target.value)}
+                                className="w-full px-3 py-1.5 bg-[#0f172a] border border-[#1f2937] rounded text-sm text-[#e5e7eb] focus:outline-none focus:border-[#38bdf8]"
+                            >
+                                <option value="none">Redact Fully</option>
+                                <option value="postcode_prefix">Keep Area Prefix (941XX)</option>
+                                <option value="country">Keep Country Only</option>
+                            </select>
+                        </div>
+                        {config.generalize === 'postcode_prefix' && (
+                            <div>
+                                <label className="block text-xs font-semibold text-[#9ca3af] mb-1">
+                                    Digits to Keep
+                                    <HelpIcon text="Leading characters kept for numeric postcodes. UK and Canadian postcodes always keep their outward code / FSA." />
+                                </label>
+                                <input
+                                    type="number"
+                                    min="0"
+                                    max="5"
+                                    value={config.keepDigits ?? 3}
+                                    onChange={(e) => handleChange('keepDigits', parseInt(e.target.value))}
+                                    className="w-full px-3 py-1.5 bg-[#0f172a] border border-[#1f2937] rounded text-sm text-[#e5e7eb] focus:outline-none focus:border-[#38bdf8]"
+                                />
+                            </div>
+                        )}
+                        {renderModeSelector()}
+                        {renderMaskOptions()}
+                    </div>
+                );
+
+            case 'geo':
+                return (
+                    <div className="space-y-3">
+                        <div>
+                            <label className="block text-xs font-semibold text-[#9ca3af] mb-1">
+                                Coordinate Handling
+                                <HelpIcon text="Detects decimal-degree pairs, DMS coordinates and lat/lon keys in JSON or query strings. Truncating keeps an approximate location instead of removing it." />
+                            </label>
+                            <select
+                                value={config.generalize || 'none'}
+                                onChange={(e) => handleChange('generalize', e.target.value)}
+                                className="w-full px-3 py-1.5 bg-[#0f172a] border border-[#1f2937] rounded text-sm text-[#e5e7eb] focus:outline-none focus:border-[#38bdf8]"
+                            >
+                                <option value="none">Redact Fully</option>
+                                <option value="truncate">Truncate Precision</option>
+                            </select>
+                        </div>
+                        {config.generalize === 'truncate' && (
+                            <div>
+                                <label className="block text-xs font-semibold text-[#9ca3af] mb-1">
+                                    Decimal Places
+                                    <HelpIcon text="2 places is roughly 1 km, 1 place roughly 10 km. DMS coordinates keep minutes from 2 places and seconds from 4." />
+                                </label>
+                                <input
+                                    type="number"
+                                    min="0"
+                                    max="6"
+                                    value={config.decimals ?? 2}
+                                    onChange={(e) => handleChange('decimals', parseInt(e.target.value))}
+                                    className="w-full px-3 py-1.5 bg-[#0f172a] border border-[#1f2937] rounded text-sm text-[#e5e7eb] focus:outline-none focus:border-[#38bdf8]"
+                                />
+                            </div>
+                        )}
+                        {renderModeSelector()}
+                        {renderMaskOptions()}
+                    </div>
+                );
+
+            case 'date':
+                return (
+                    <div className="space-y-3">
+                        <div>
+                            <label className="block text-xs font-semibold text-[#9ca3af] mb-1">
+                                Date Handling
+                                <HelpIcon text="Shifting moves every date by the same random number of days for this session, so intervals between events stay intact." />
+                            </label>
+                            <select
+                                value={config.dateMode || 'redact'}
+                                onChange={(e) => handleChange('dateMode', e.target.value)}
+                                className="w-full px-3 py-1.5 bg-[#0f172a] border border-[#1f2937] rounded text-sm text-[#e5e7eb] focus:outline-none focus:border-[#38bdf8]"
+                            >
+                                <option value="redact">Redact</option>
+                                <option value="year">Generalize to Year</option>
+                                <option value="month">Generalize to Month</option>
+                                <option value="shift">Shift by Session Offset</option>
+                            </select>
+                        </div>
+                        {config.dateMode === 'shift' && (
+                            <div>
+                                <label className="block text-xs font-semibold text-[#9ca3af] mb-1">
+                                    Maximum Shift (days)
+                                    <HelpIcon text="Upper bound for the per-session offset, applied forwards or backwards." />
+                                </label>
+                                <input
+                                    type="number"
+                                    min="1"
+                                    value={config.maxShiftDays ?? 365}
+                                    onChange={(e) => handleChange('maxShiftDays', parseInt(e.target.value))}
+                                    className="w-full px-3 py-1.5 bg-[#0f172a] border border-[#1f2937] rounded text-sm text-[#e5e7eb] focus:outline-none focus:border-[#38bdf8]"
+                                />
+                            </div>
+                        )}
+                        {renderToggle('dayFirst', 'Day-first slash dates (DD/MM/YYYY)', 'Read 03/07/2024 as 3 July instead of March 7. Dotted dates (03.07.2024) are always day-first.')}
+                        {(!config.dateMode || config.dateMode === 'redact') && renderModeSelector()}
+                        {(!config.dateMode || config.dateMode === 'redact') && renderMaskOptions()}
+                    </div>
+                );
+
             case 'jsonKey':
             case 'queryParam':
             case 'header':
@@ -204,6 +865,44 @@ export const StepConfig: React.FC<StepConfigProps> = ({ type, config, label, onL
                     </div>
                 );
 
+            case 'dictionary':
+                return (
+                    <div className="space-y-3">
+                        <div>
+                            <label className="block text-xs font-semibold text-[#9ca3af] mb-1">
+                                Terms (one per line)
+                                <HelpIcon text="Customer names, employee names or code words. Thousands of terms are matched in a single pass, and each term keeps one token however it is capitalized." />
+                            </label>
+                            <textarea
+                                rows={5}
+                                value={Array.isArray(config.terms) ? config.terms.join('\n') : (config.terms || '')}
+                                onChange={(e) => handleChange('terms', e.target.value)}
+                                placeholder={'Acme Corp\nProject Falcon'}
+                                className="w-full px-3 py-1.5 bg-[#0f172a] border border-[#1f2937] rounded text-sm text-[#e5e7eb] font-mono focus:outline-none focus:border-[#38bdf8]"
+                            />
+                            <label className="mt-1 inline-block text-[10px] uppercase font-bold tracking-wider text-[#38bdf8] cursor-pointer hover:underline">
+                                Load Word List
+                                <input
+                                    type="file"
+                                    accept=".txt,.csv,.lst"
+                                    className="hidden"
+                                    onChange={async (e) => {
+                                        const file = e.target.files?.[0];
+                                        if (file) handleChange('terms', await file.text());
+                                        e.target.value = '';
+                                    }}
+                                />
+                            </label>
+                        </div>
+                        <div className="space-y-1.5">
+                            {renderToggle('wholeWord', 'Whole Words Only', 'Skip matches that are part of a longer word, e.g. "Falcon" inside "falconry".', true)}
+                            {renderToggle('caseInsensitive', 'Ignore Case', 'Match terms regardless of capitalization.', true)}
+                        </div>
+                        {renderModeSelector()}
+                        {renderMaskOptions()}
+                    </div>
+                );
+
             case 'partialMask':
                 return (
                     <div className="space-y-3">
@@ -10,11 +10,13 @@ interface TokenProps {
     contextBefore?: string;
     contextAfter?: string;
     method?: string;
+    entropy?: number;
+    confidence?: number;
 }
 
 export const Token: React.FC<TokenProps> = ({
     id, original, count, type, isHighlighted, onHighlight,
-    contextBefore, contextAfter, method
+    contextBefore, contextAfter, method, entropy, confidence
 }) => {
     const [showTooltip, setShowTooltip] = useState(false);
 
@@ -23,7 +25,7 @@ export const Token: React.FC<TokenProps> = ({
         const upper = t.toUpperCase();
 
         // Identity (Blue)
-        if (upper.includes('EMAIL') || upper.includes('PHONE') || upper.includes('USERNAME'))
+        if (upper.includes('EMAIL') || upper.includes('PHONE') || upper.includes('USERNAME') || upper.startsWith('NAME_'))
             return 'border-[#38bdf8] text-[#38bdf8] bg-[#38bdf8]/10';
 
         // Infrastructure (Green)
@@ -31,11 +33,13 @@ export const Token: React.FC<TokenProps> = ({
             return 'border-[#10b981] text-[#10b981] bg-[#10b981]/10';
 
         // Secrets (Red)
-        if (upper.includes('JWT') || upper.includes('KEY') || upper.includes('TOKEN') || upper.includes('BASE64'))
+        if (upper.includes('JWT') || upper.includes('KEY') || upper.includes('TOKEN') || upper.includes('BASE64') ||
+            upper.includes('SECRET') || upper.includes('PASSWORD') || upper.includes('AUTH'))
             return 'border-[#ef4444] text-[#ef4444] bg-[#ef4444]/10';
 
         // PII/Financial (Orange)
-        if (upper.includes('SSN') || upper.includes('CC') || upper.includes('CREDIT'))
+        if (upper.includes('SSN') || upper.includes('CC') || upper.includes('CREDIT') ||
+            upper.includes('IBAN') || upper.includes('BIC') || upper.includes('ROUTING') || upper.includes('WALLET'))
             return 'border-[#f59e0b] text-[#f59e0b] bg-[#f59e0b]/10';
 
         // UUID (Cyan)
@@ -83,6 +87,22 @@ export const Token: React.FC<TokenProps> = ({
                             </div>
                         )}
 
+                        {/* Entropy Score */}
+                        {entropy !== undefined && (
+                            <div className="flex items-center gap-1.5 font-mono text-[9px] text-[#9ca3af]">
+                                <span className="uppercase opacity-50">Entropy:</span>
+                                <span className="text-[#f59e0b]">{entropy.toFixed(2)} bits/char</span>
+                            </div>
+                        )}
+
+                        {/* Heuristic Confidence */}
+                        {confidence !== undefined && (
+                            <div className="flex items-center gap-1.5 font-mono text-[9px] text-[#9ca3af]">
+                                <span className="uppercase opacity-50">Confidence:</span>
+                                <span className="text-[#f59e0b]">{Math.round(confidence * 100)}%</span>
+                            </div>
+                        )}
+
                         {/* Context Area */}
                         {(contextBefore || contextAfter) && (
                             <div className="bg-[#0f172a] p-1.5 rounded-lg border border-[#334155]/30">
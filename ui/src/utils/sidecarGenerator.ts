@@ -140,6 +140,11 @@ export const generateMappingSidecar = (
             filter: none;
             background: rgba(255,255,255,0.05);
         }
+        .variant {
+            margin-top: 0.25rem;
+            font-size: 0.75rem;
+            color: var(--text-secondary);
+        }
         .search-box {
             width: 100%;
             padding: 1rem;
@@ -211,7 +216,12 @@ export const generateMappingSidecar = (
                             <span class="context-text">${value.context_after || ''}</span>
                         </div>
                     </td>
-                    <td><span class="original" onclick="this.classList.toggle('revealed')">${value.original}</span></td>
+                    <td>
+                        <span class="original" onclick="this.classList.toggle('revealed')">${value.original}</span>
+                        ${(value.variants || []).filter((v: string) => v !== value.original).map((v: string) => `
+                        <div class="variant"><span class="original" onclick="this.classList.toggle('revealed')">${v}</span></div>
+                        `).join('')}
+                    </td>
                     <td>${value.occurrences}</td>
                 </tr>
                 `).join('')}